@@ -0,0 +1,528 @@
+//! Derive macros for the [stability declaration traits](https://jswrenn.github.io/safer-transmute-rustdoc/rfc/core/convert/transmute/stability/index.html) of the [safer transmute RFC](https://github.com/rust-lang/project-safe-transmute/pull/5).
+//!
+//! Each derive generates, in an anonymous `const` block, an archetype of the annotated type: a type with the same `repr` and the same shape, in which the type of every field is replaced by that type's archetype. The annotated type then declares that archetype as its own. For instance, this:
+//! ```ignore
+//! #[derive(PromiseTransmutableInto)]
+//! #[repr(C)]
+//! pub struct Foo(pub Bar, pub Baz);
+//! ```
+//! expands to this:
+//! ```ignore
+//! const _: () = {
+//!     #[repr(C)]
+//!     pub struct TransmutableIntoArchetype(
+//!         pub <Bar as PromiseTransmutableInto>::Archetype,
+//!         pub <Baz as PromiseTransmutableInto>::Archetype,
+//!     );
+//!
+//!     // A stand-in for the impl of `TransmuteFrom` that the compiler generates.
+//!     const _: () = assert!(size_of::<Foo>() == size_of::<TransmutableIntoArchetype>() && align_of::<Foo>() == align_of::<TransmutableIntoArchetype>());
+//!     unsafe impl TransmuteFrom<Foo, NeglectStability> for TransmutableIntoArchetype {}
+//!
+//!     impl PromiseTransmutableInto for TransmutableIntoArchetype { type Archetype = Self; }
+//!
+//!     impl PromiseTransmutableInto for Foo { type Archetype = TransmutableIntoArchetype; }
+//!
+//!     // ...and impls of `LayoutInfo` for `Foo` and its archetype.
+//! };
+//! ```
+//!
+//! Transmutations between a type and its archetypes are recognized by the compiler; on stable, the derives implement [`TransmuteFrom`](https://jswrenn.github.io/safer-transmute-rustdoc/rfc/core/convert/transmute/trait.TransmuteFrom.html) between them directly, having checked at compile time that their sizes and alignments agree.
+//!
+//! Since the layout of a type is only part of its public API if it is defined and visible, the derives reject:
+//!  - structs that are not `#[repr(C)]` or `#[repr(transparent)]`,
+//!  - unions that are not `#[repr(C)]`,
+//!  - enums that are not `#[repr(C)]` or `#[repr(<integer>)]`,
+//!  - structs and unions with fields that are not `pub`, and
+//!  - generic types.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, Ident, Index,
+    LitStr, Meta, Visibility,
+};
+
+/// Derive macro generating impls of the traits `PromiseTransmutableInto` and `LayoutInfo`.
+///
+/// To promise that all safe transmutations from your type into other `PromiseTransmutableFrom` types will remain safe in the future, simply annotate your type with `#[derive(PromiseTransmutableInto)]`.
+///
+/// For instance, this:
+/// ```ignore
+/// #[derive(PromiseTransmutableInto)]
+/// #[repr(C)]
+/// pub struct Foo(pub Bar, pub Baz);
+/// ```
+/// will expand to this:
+/// ```ignore
+/// /// Generated `PromiseTransmutableInto` for `Foo`
+/// const _: () = {
+///     use rfc::core::convert::transmute::{TransmuteFrom, options::NeglectStability};
+///     use rfc::core::convert::transmute::stability::{PromiseTransmutableInto, LayoutInfo, TypeLayout};
+///
+///     #[repr(C)]
+///     pub struct TransmutableIntoArchetype(
+///         pub <Bar as PromiseTransmutableInto>::Archetype,
+///         pub <Baz as PromiseTransmutableInto>::Archetype,
+///     );
+///
+///     const _: () = assert!(
+///         size_of::<Foo>() == size_of::<TransmutableIntoArchetype>()
+///             && align_of::<Foo>() == align_of::<TransmutableIntoArchetype>(),
+///         "the size and alignment of `TransmutableIntoArchetype` must equal those of `Foo`"
+///     );
+///
+///     unsafe impl TransmuteFrom<Foo, NeglectStability> for TransmutableIntoArchetype {}
+///
+///     impl PromiseTransmutableInto for TransmutableIntoArchetype { type Archetype = Self; }
+///
+///     impl PromiseTransmutableInto for Foo {
+///         type Archetype = TransmutableIntoArchetype;
+///     }
+///
+///     impl LayoutInfo for TransmutableIntoArchetype {
+///         fn layout_info() -> TypeLayout {
+///             TypeLayout::of::<Self>()
+///                 .field::<<Bar as PromiseTransmutableInto>::Archetype>("0", offset_of!(Self, 0))
+///                 .field::<<Baz as PromiseTransmutableInto>::Archetype>("1", offset_of!(Self, 1))
+///         }
+///     }
+///
+///     impl LayoutInfo for Foo {
+///         fn layout_info() -> TypeLayout {
+///             TypeLayout::of::<Self>()
+///                 .field::<Bar>("0", offset_of!(Self, 0))
+///                 .field::<Baz>("1", offset_of!(Self, 1))
+///                 .archetype::<Self>()
+///         }
+///     }
+/// };
+/// ```
+/// Every stably-transmutable type in `rfc` implements `LayoutInfo`, as does every type that derives `PromiseTransmutableInto`, so the fields of a derived type ordinarily implement it too.
+///
+/// Enums and unions are supported too; see `rfc::promise_transmutable!` for how their archetypes are generated.
+#[proc_macro_derive(PromiseTransmutableInto)]
+pub fn derive_promise_transmutable_into(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, &[Direction::Into])
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating an impl of the trait `PromiseTransmutableFrom`.
+///
+/// To promise that all transmutations of any `PromiseTransmutableInto` type into your type that are currently safe will remain so in the future, simply annotate your type with `#[derive(PromiseTransmutableFrom)]`.
+///
+/// For instance, this:
+/// ```ignore
+/// #[derive(PromiseTransmutableFrom)]
+/// #[repr(C)]
+/// pub struct Foo(pub Bar, pub Baz);
+/// ```
+/// will expand to this:
+/// ```ignore
+/// /// Generated `PromiseTransmutableFrom` for `Foo`
+/// const _: () = {
+///     use rfc::core::convert::transmute::{TransmuteFrom, options::NeglectStability};
+///     use rfc::core::convert::transmute::stability::PromiseTransmutableFrom;
+///
+///     #[repr(C)]
+///     pub struct TransmutableFromArchetype(
+///         pub <Bar as PromiseTransmutableFrom>::Archetype,
+///         pub <Baz as PromiseTransmutableFrom>::Archetype,
+///     );
+///
+///     const _: () = assert!(
+///         size_of::<Foo>() == size_of::<TransmutableFromArchetype>()
+///             && align_of::<Foo>() == align_of::<TransmutableFromArchetype>(),
+///         "the size and alignment of `TransmutableFromArchetype` must equal those of `Foo`"
+///     );
+///
+///     unsafe impl TransmuteFrom<TransmutableFromArchetype, NeglectStability> for Foo {}
+///
+///     impl PromiseTransmutableFrom for TransmutableFromArchetype { type Archetype = Self; }
+///
+///     impl PromiseTransmutableFrom for Foo {
+///         type Archetype = TransmutableFromArchetype;
+///     }
+/// };
+/// ```
+/// Enums and unions are supported too; see `rfc::promise_transmutable!` for how their archetypes are generated.
+///
+/// The derive also stands in for the compiler's impls of `TryTransmuteFrom`: a fieldless enum with a `#[repr(<integer>)]` becomes fallibly transmutable from that integer and from its bytes, and a struct becomes fallibly transmutable from its bytes if each of its fields is fallibly transmutable from theirs.
+#[proc_macro_derive(PromiseTransmutableFrom)]
+pub fn derive_promise_transmutable_from(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, &[Direction::From])
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro generating impls of *both* `PromiseTransmutableFrom` and `PromiseTransmutableInto`.
+///
+/// This is just a shorthand for deriving both `PromiseTransmutableFrom` and `PromiseTransmutableInto`.
+///
+/// For more information on this extension, [**see here**](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#extension-promisetransmutable-shorthand).
+#[proc_macro_derive(PromiseTransmutable)]
+pub fn derive_promise_transmutable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, &[Direction::Into, Direction::From])
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The stability declaration trait being derived.
+#[derive(Clone, Copy)]
+enum Direction {
+    Into,
+    From,
+}
+
+impl Direction {
+    fn trait_path(self) -> TokenStream2 {
+        match self {
+            Direction::Into => quote!(::rfc::core::convert::transmute::stability::PromiseTransmutableInto),
+            Direction::From => quote!(::rfc::core::convert::transmute::stability::PromiseTransmutableFrom),
+        }
+    }
+
+    fn archetype(self) -> Ident {
+        match self {
+            Direction::Into => Ident::new("TransmutableIntoArchetype", Span::call_site()),
+            Direction::From => Ident::new("TransmutableFromArchetype", Span::call_site()),
+        }
+    }
+
+    // A stand-in for the impl of `TransmuteFrom` that the compiler generates between a type and its archetype: `Self::Archetype` must be transmutable from `Self` (for `PromiseTransmutableInto`), or into it (for `PromiseTransmutableFrom`). Since the archetype has exactly the layout of the type, this is checked only by comparing their sizes and alignments.
+    fn transmute_impl(self, name: &Ident, archetype: &Ident) -> TokenStream2 {
+        let transmute_from = quote!(::rfc::core::convert::transmute::TransmuteFrom);
+        let neglect_stability = quote!(::rfc::core::convert::transmute::options::NeglectStability);
+        let message = LitStr::new(
+            &format!("the size and alignment of `{}` must equal those of `{}`", archetype, name),
+            Span::call_site(),
+        );
+        let (src, dst) = match self {
+            Direction::Into => (name, archetype),
+            Direction::From => (archetype, name),
+        };
+        quote! {
+            const _: () = ::core::assert!(
+                ::core::mem::size_of::<#name>() == ::core::mem::size_of::<#archetype>()
+                    && ::core::mem::align_of::<#name>() == ::core::mem::align_of::<#archetype>(),
+                #message
+            );
+
+            unsafe impl #transmute_from<#src, #neglect_stability> for #dst {}
+        }
+    }
+}
+
+fn expand(input: &DeriveInput, directions: &[Direction]) -> Result<TokenStream2, Error> {
+    check(input)?;
+
+    let name = &input.ident;
+    let reprs: Vec<&Attribute> = input.attrs.iter().filter(|attr| attr.path().is_ident("repr")).collect();
+
+    let mut items = TokenStream2::new();
+    for &direction in directions {
+        let trait_path = direction.trait_path();
+        let archetype = direction.archetype();
+        let definition = archetype_definition(input, &archetype, &trait_path);
+        let transmute_impl = direction.transmute_impl(name, &archetype);
+        // The archetype is never constructed; it only exemplifies a layout.
+        items.extend(quote! {
+            #[allow(dead_code)]
+            #(#reprs)*
+            #definition
+
+            #transmute_impl
+
+            impl #trait_path for #archetype { type Archetype = Self; }
+
+            impl #trait_path for #name { type Archetype = #archetype; }
+        });
+        match direction {
+            Direction::Into => items.extend(layout_info(input, &archetype, &trait_path)),
+            Direction::From => items.extend(try_transmute_from(input)?),
+        }
+    }
+
+    Ok(quote! {
+        const _: () = {
+            #items
+        };
+    })
+}
+
+// The primitive representations of enums.
+const INTEGERS: [&str; 12] = ["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+
+// Reject types whose layouts are not fully defined and public.
+fn check(input: &DeriveInput) -> Result<(), Error> {
+    let mut errors: Vec<Error> = Vec::new();
+
+    if !input.generics.params.is_empty() {
+        errors.push(Error::new_spanned(
+            &input.generics,
+            "stability declarations cannot be derived for generic types; implement them manually",
+        ));
+    }
+
+    let reprs = repr_hints(&input.attrs)?;
+    let has = |hint: &str| reprs.iter().any(|ident| ident == hint);
+    let has_int = reprs.iter().any(|ident| INTEGERS.iter().any(|int| ident == int));
+    // Errors about the representation point at the `#[repr(...)]` attribute, or, if there is none, at the type's name.
+    let repr_error = |message: String| match input.attrs.iter().find(|attr| attr.path().is_ident("repr")) {
+        Some(attr) => Error::new_spanned(attr, message),
+        None => Error::new(input.ident.span(), message),
+    };
+
+    let (accepted, expected) = match &input.data {
+        Data::Struct(_) => (has("C") || has("transparent"), "`#[repr(C)]` or `#[repr(transparent)]`"),
+        Data::Union(_) => (has("C"), "`#[repr(C)]`"),
+        Data::Enum(_) => (has("C") || has_int, "`#[repr(C)]` or a primitive representation, e.g. `#[repr(u8)]`"),
+    };
+    if !accepted {
+        errors.push(repr_error(format!(
+            "the layout of this type is not defined; stability declarations require {}",
+            expected
+        )));
+    }
+
+    let fields: Vec<&syn::Field> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Union(data) => data.fields.named.iter().collect(),
+        // The fields of enum variants are always public.
+        Data::Enum(_) => Vec::new(),
+    };
+    for field in fields {
+        if !matches!(field.vis, Visibility::Public(_)) {
+            errors.push(Error::new_spanned(
+                field,
+                "this field is not `pub`; the layout of a type with private fields is not part of its public API",
+            ));
+        }
+    }
+
+    match errors.into_iter().reduce(|mut combined, error| {
+        combined.combine(error);
+        combined
+    }) {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+// The hints of every `#[repr(...)]` attribute; e.g., `C` and `align` for `#[repr(C, align(8))]`.
+fn repr_hints(attrs: &[Attribute]) -> Result<Vec<Ident>, Error> {
+    let mut hints = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        if let Meta::List(_) = attr.meta {
+            attr.parse_nested_meta(|meta| {
+                if let Some(ident) = meta.path.get_ident() {
+                    hints.push(ident.clone());
+                }
+                // Skip the arguments of `align(N)` and `packed(N)`.
+                if meta.input.peek(syn::token::Paren) {
+                    meta.input.parse::<proc_macro2::Group>()?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(hints)
+}
+
+// The definition of the archetype: `input`, with the type of every field replaced by its archetype.
+fn archetype_definition(input: &DeriveInput, archetype: &Ident, trait_path: &TokenStream2) -> TokenStream2 {
+    // The fields of enum variants may not have visibility qualifiers; they are always public.
+    let archetype_fields = |fields: &Fields, wrap: bool, vis: bool| -> TokenStream2 {
+        let vis = if vis { quote!(pub) } else { quote!() };
+        let field_type = |ty: &syn::Type| {
+            if wrap {
+                quote!(::core::mem::ManuallyDrop<<#ty as #trait_path>::Archetype>)
+            } else {
+                quote!(<#ty as #trait_path>::Archetype)
+            }
+        };
+        match fields {
+            Fields::Named(fields) => {
+                let fields = fields.named.iter().map(|field| {
+                    let ident = &field.ident;
+                    let ty = field_type(&field.ty);
+                    quote!(#vis #ident: #ty)
+                });
+                quote!({ #(#fields,)* })
+            }
+            Fields::Unnamed(fields) => {
+                let fields = fields.unnamed.iter().map(|field| {
+                    let ty = field_type(&field.ty);
+                    quote!(#vis #ty)
+                });
+                quote!(( #(#fields,)* ))
+            }
+            Fields::Unit => quote!(),
+        }
+    };
+
+    match &input.data {
+        Data::Struct(data) => {
+            let fields = archetype_fields(&data.fields, false, true);
+            match data.fields {
+                Fields::Named(_) => quote!(pub struct #archetype #fields),
+                _ => quote!(pub struct #archetype #fields;),
+            }
+        }
+        Data::Union(data) => {
+            let fields = archetype_fields(&Fields::Named(data.fields.clone()), true, true);
+            quote!(pub union #archetype #fields)
+        }
+        Data::Enum(data) => {
+            let variants = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let fields = archetype_fields(&variant.fields, false, false);
+                let discriminant = variant.discriminant.as_ref().map(|(eq, expr)| quote!(#eq #expr));
+                quote!(#ident #fields #discriminant)
+            });
+            quote!(pub enum #archetype { #(#variants,)* })
+        }
+    }
+}
+
+// The impls of `LayoutInfo` for `input` and its archetype.
+fn layout_info(input: &DeriveInput, archetype: &Ident, trait_path: &TokenStream2) -> TokenStream2 {
+    let name = &input.ident;
+    let layout_info = quote!(::rfc::core::convert::transmute::stability::LayoutInfo);
+    let type_layout = quote!(::rfc::core::convert::transmute::stability::TypeLayout);
+
+    // The fields of a struct or union, as calls to `TypeLayout::field`; the variants of an enum overlap, so an enum's layout is reported without fields.
+    let fields = |archetypal: bool| -> Vec<TokenStream2> {
+        let field_type = |ty: &syn::Type| {
+            if archetypal {
+                quote!(<#ty as #trait_path>::Archetype)
+            } else {
+                quote!(#ty)
+            }
+        };
+        match &input.data {
+            Data::Struct(data) => data
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let ty = field_type(&field.ty);
+                    match &field.ident {
+                        Some(ident) => quote!(.field::<#ty>(stringify!(#ident), ::core::mem::offset_of!(Self, #ident))),
+                        None => {
+                            let index = Index::from(i);
+                            quote!(.field::<#ty>(stringify!(#index), ::core::mem::offset_of!(Self, #index)))
+                        }
+                    }
+                })
+                .collect(),
+            Data::Union(data) => data
+                .fields
+                .named
+                .iter()
+                .map(|field| {
+                    let ident = &field.ident;
+                    let ty = field_type(&field.ty);
+                    quote!(.field::<#ty>(stringify!(#ident), 0))
+                })
+                .collect(),
+            Data::Enum(_) => Vec::new(),
+        }
+    };
+    let archetype_fields = fields(true);
+    let fields = fields(false);
+
+    quote! {
+        impl #layout_info for #archetype {
+            fn layout_info() -> #type_layout {
+                #type_layout::of::<Self>()
+                    #(#archetype_fields)*
+            }
+        }
+
+        impl #layout_info for #name {
+            fn layout_info() -> #type_layout {
+                #type_layout::of::<Self>()
+                    #(#fields)*
+                    .archetype::<Self>()
+            }
+        }
+    }
+}
+
+// Stand-ins for the impls of `TryTransmuteFrom` that the compiler generates for types that promise to be stably transmutable *from* other types: for a fieldless enum with a primitive representation, from that integer and from its bytes; for a struct, from its bytes, if each of its fields may be fallibly transmuted from theirs.
+fn try_transmute_from(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let try_transmute_from = quote!(::rfc::core::convert::transmute::TryTransmuteFrom);
+    let size_of = |ty: &TokenStream2| quote!(::core::mem::size_of::<#ty>());
+
+    match &input.data {
+        Data::Enum(data) if data.variants.iter().all(|variant| matches!(variant.fields, Fields::Unit)) => {
+            let repr = repr_hints(&input.attrs)?.into_iter().find(|ident| INTEGERS.iter().any(|int| ident == int));
+            let repr = match repr {
+                Some(repr) => repr,
+                // The discriminant of a `#[repr(C)]` enum has the platform-dependent size of a C `enum`.
+                None => return Ok(TokenStream2::new()),
+            };
+            let variants = data.variants.iter().map(|variant| &variant.ident);
+            let bytes = size_of(&quote!(#repr));
+            Ok(quote! {
+                unsafe impl #try_transmute_from<#repr> for #name {
+                    #[inline(always)]
+                    fn is_bit_valid(src: &#repr) -> bool {
+                        false #(|| *src == Self::#variants as #repr)*
+                    }
+                }
+
+                unsafe impl #try_transmute_from<[u8; #bytes]> for #name {
+                    #[inline(always)]
+                    fn is_bit_valid(src: &[u8; #bytes]) -> bool {
+                        <Self as #try_transmute_from<#repr>>::is_bit_valid(&#repr::from_ne_bytes(*src))
+                    }
+                }
+            })
+        }
+        Data::Struct(data) => {
+            let bytes = size_of(&quote!(Self));
+            let types: Vec<&syn::Type> = data.fields.iter().map(|field| &field.ty).collect();
+            let checks = data.fields.iter().enumerate().map(|(i, field)| {
+                let ty = &field.ty;
+                let member = match &field.ident {
+                    Some(ident) => quote!(#ident),
+                    None => {
+                        let index = Index::from(i);
+                        quote!(#index)
+                    }
+                };
+                let field_bytes = size_of(&quote!(#ty));
+                quote! {{
+                    let offset = ::core::mem::offset_of!(Self, #member);
+                    let mut field = [0u8; #field_bytes];
+                    field.copy_from_slice(&src[offset..offset + #field_bytes]);
+                    <#ty as #try_transmute_from<[u8; #field_bytes]>>::is_bit_valid(&field)
+                }}
+            });
+            let field_bytes = types.iter().map(|ty| size_of(&quote!(#ty)));
+            // The bounds are higher-ranked so that, rather than being rejected as trivially unsatisfiable, the impl is merely absent for structs with fields that cannot be checked.
+            Ok(quote! {
+                unsafe impl #try_transmute_from<[u8; #bytes]> for #name
+                where
+                    #(for<'a> #types: #try_transmute_from<[u8; #field_bytes]>,)*
+                {
+                    #[inline(always)]
+                    fn is_bit_valid(src: &[u8; #bytes]) -> bool {
+                        true #(&& #checks)*
+                    }
+                }
+            })
+        }
+        _ => Ok(TokenStream2::new()),
+    }
+}
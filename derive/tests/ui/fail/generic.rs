@@ -0,0 +1,7 @@
+use rfc::core::convert::transmute::stability::*;
+
+#[derive(PromiseTransmutable)]
+#[repr(C)]
+pub struct Foo<T>(pub T);
+
+fn main() {}
@@ -0,0 +1,20 @@
+use rfc::core::convert::transmute::stability::*;
+
+#[derive(PromiseTransmutableInto)]
+pub struct Foo {
+    pub a: u8,
+}
+
+#[derive(PromiseTransmutableFrom)]
+#[repr(align(4))]
+pub union Bar {
+    pub a: u8,
+}
+
+#[derive(PromiseTransmutable)]
+pub enum Baz {
+    A,
+    B,
+}
+
+fn main() {}
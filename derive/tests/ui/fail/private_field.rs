@@ -0,0 +1,10 @@
+use rfc::core::convert::transmute::stability::*;
+
+#[derive(PromiseTransmutable)]
+#[repr(C)]
+pub struct Foo {
+    pub a: u8,
+    b: u16,
+}
+
+fn main() {}
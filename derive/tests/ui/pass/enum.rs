@@ -0,0 +1,37 @@
+#![deny(warnings)]
+
+use rfc::core::convert::transmute::stability::*;
+
+#[derive(PromiseTransmutable)]
+#[repr(u8)]
+pub enum Fieldless {
+    A = 1,
+    B = 2,
+}
+
+#[derive(PromiseTransmutable)]
+#[repr(u8)]
+pub enum Message {
+    Data(u16) = 1,
+    Ack { seq: u32 } = 2,
+    Close = 3,
+}
+
+#[derive(PromiseTransmutable)]
+#[repr(C)]
+pub enum Shape {
+    Circle(f32),
+    Rect { w: f32, h: f32 },
+}
+
+fn promised<T: PromiseTransmutableInto + PromiseTransmutableFrom>() {}
+
+fn main() {
+    promised::<Fieldless>();
+    promised::<Message>();
+    promised::<Shape>();
+
+    assert_eq!(Fieldless::layout_info().size, 1);
+    assert_eq!(Message::layout_info().size, core::mem::size_of::<Message>());
+    assert_eq!(Shape::layout_info().align, core::mem::align_of::<Shape>());
+}
@@ -0,0 +1,48 @@
+use rfc::core::convert::transmute::stability::*;
+
+#[derive(PromiseTransmutable)]
+#[repr(C)]
+pub struct Named {
+    pub a: u8,
+    pub b: u16,
+}
+
+#[derive(PromiseTransmutable)]
+#[repr(C)]
+pub struct Tuple(pub u8, pub Named);
+
+#[derive(PromiseTransmutableInto)]
+#[repr(transparent)]
+pub struct Transparent(pub u64);
+
+#[derive(PromiseTransmutableFrom)]
+#[repr(C, align(8))]
+pub struct Aligned(pub u32);
+
+// Unlike `promise_transmutable!`, the derives are not limited in the number of fields.
+#[derive(PromiseTransmutable)]
+#[repr(C)]
+pub struct Wide(
+    pub u8, pub u8, pub u8, pub u8, pub u8, pub u8, pub u8, pub u8, pub u8,
+    pub u8, pub u8, pub u8, pub u8, pub u8, pub u8, pub u8, pub u8, pub u8,
+);
+
+fn promised<T: PromiseTransmutableInto + PromiseTransmutableFrom>() {}
+
+fn main() {
+    promised::<Named>();
+    promised::<Tuple>();
+    promised::<Wide>();
+
+    let layout = Named::layout_info();
+    assert_eq!((layout.size, layout.align), (4, 2));
+    assert_eq!(layout.padding(), vec![1..2]);
+    assert!(layout.archetype.is_some());
+
+    let layout = Tuple::layout_info();
+    assert_eq!(layout.fields[1].offset, 2);
+    assert_eq!(layout.fields[1].layout.fields[1].offset, 2);
+
+    assert_eq!(Transparent::layout_info().size, 8);
+    assert_eq!(Wide::layout_info().fields.len(), 18);
+}
@@ -0,0 +1,48 @@
+use rfc::core::convert::transmute::{TryTransmuteFrom, stability::*};
+
+#[derive(PromiseTransmutable, Debug, PartialEq)]
+#[repr(u16)]
+pub enum Opcode {
+    Nop = 0,
+    Jump = 0x4C,
+}
+
+#[derive(PromiseTransmutable, Debug, PartialEq)]
+#[repr(C)]
+pub struct Instruction {
+    pub op: Opcode,
+    pub flag: bool,
+    pub arg: u8,
+}
+
+// A field that cannot be checked does not prevent the derive; `Unchecked` is merely not fallibly transmutable.
+#[derive(PromiseTransmutable)]
+#[repr(C)]
+pub struct Unchecked {
+    pub bytes: [u8; 2],
+}
+
+// Enums without a primitive representation, and enums with fields, are not fallibly transmutable either.
+#[derive(PromiseTransmutable)]
+#[repr(C)]
+pub enum Plain {
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(<Opcode as TryTransmuteFrom<u16>>::try_transmute_from(0x4C).ok(), Some(Opcode::Jump));
+    assert!(<Opcode as TryTransmuteFrom<u16>>::try_transmute_from(1).is_err());
+    assert_eq!(
+        <Opcode as TryTransmuteFrom<[u8; 2]>>::try_transmute_from(0u16.to_ne_bytes()).ok(),
+        Some(Opcode::Nop),
+    );
+
+    let op = 0x4Cu16.to_ne_bytes();
+    assert_eq!(
+        <Instruction as TryTransmuteFrom<[u8; 4]>>::try_transmute_from([op[0], op[1], 1, 7]).ok(),
+        Some(Instruction { op: Opcode::Jump, flag: true, arg: 7 }),
+    );
+    assert!(<Instruction as TryTransmuteFrom<[u8; 4]>>::try_transmute_from([op[0], op[1], 2, 7]).is_err());
+    assert!(<Instruction as TryTransmuteFrom<[u8; 4]>>::try_transmute_from([1, 1, 1, 7]).is_err());
+}
@@ -0,0 +1,18 @@
+use rfc::core::convert::transmute::stability::*;
+
+#[derive(PromiseTransmutable)]
+#[repr(C)]
+pub union Word {
+    pub bytes: [u8; 4],
+    pub value: u32,
+}
+
+fn promised<T: PromiseTransmutableInto + PromiseTransmutableFrom>() {}
+
+fn main() {
+    promised::<Word>();
+
+    let layout = Word::layout_info();
+    assert_eq!((layout.size, layout.align), (4, 4));
+    assert!(layout.fields.iter().all(|field| field.offset == 0));
+}
@@ -1,19 +1,16 @@
-#![feature(untagged_unions,const_fn,const_fn_union)] // for the impl of transmute free functions
-#![feature(const_generics)] // for stability declarations on `[T; N]`
-#![feature(decl_macro)] // for stub implementations of derives
-#![feature(never_type)] // for stability declarations on `!`
-#![feature(marker_trait_attr)] // for cast extension
-#![cfg_attr(doc, feature(staged_api))] // for `unstable` attribute
-#![feature(optin_builtin_traits)] // for `mem` gadgets demo
-#![feature(vec_into_raw_parts)] // for vec casting demo
-#![feature(impl_trait_in_bindings)]
-#![allow(unused_unsafe, incomplete_features)]
+#![cfg_attr(feature = "nightly-never-type", feature(never_type))] // for stability declarations on `!`
+#![cfg_attr(feature = "nightly-marker-traits", feature(marker_trait_attr))] // for extensible cast options
+#![cfg_attr(all(doc, feature = "nightly-staged-api"), feature(staged_api), allow(internal_features))] // for `unstable` attribute
+#![allow(unused_unsafe)]
 #![deny(missing_docs)]
+#![allow(clippy::missing_safety_doc)] // safety obligations are stated in prose, as in the RFC
 
 //! Rustdoc for the API surface proposed by the [safer transmute RFC](https://github.com/rust-lang/project-safe-transmute/pull/5).
 //!
 //! New modules and new items within existing modules are marked with **🌟**. Items associated with RFC extensions are marked unstable.
 //!
+//! The [layout] module is not part of the proposed API; it is an executable model of the rules by which the compiler implements [TransmuteFrom][core::convert::transmute::TransmuteFrom].
+//!
 //! <style>h2#macros, h2#macros + table, a[href="#macros"] { display: none!important; }</style>
 
 /// Additions to libcore.
@@ -24,13 +21,14 @@ pub mod core {
         #[doc(no_inline)]
         pub use core::convert::*;
 
+        #[allow(unused_imports)] // for intra-doc links
         use transmute::*;
 
         /// **🌟** **Bit-preserving conversions.**
         ///
         /// Transmutation is the act of reinterpreting the bytes corresponding to a value of one type as if they corresponded to a different type. A transmutation of a `Src` to a `Dst` type is similar to defining a union with two variants:
         /// ```rust
-        /// #![feature(untagged_unions)]
+        /// # use core::mem::ManuallyDrop;
         /// union Transmute<Src, Dst> {
         ///     src: ManuallyDrop<Src>,
         ///     dst: ManuallyDrop<Dst>,
@@ -46,7 +44,7 @@ pub mod core {
         /// ## Example
         /// Given:
         /// ```rust
-        /// use core::convert::transmute::{
+        /// use rfc::core::convert::transmute::{
         ///     TransmuteInto,
         ///     stability::{PromiseTransmutableInto, PromiseTransmutableFrom},
         /// };
@@ -56,13 +54,17 @@ pub mod core {
         /// pub struct Foo(pub u8, pub u16);
         /// //                    ^ there's a padding byte here, between these fields
         /// ```
-        /// This transmutation accepted:
-        /// ```rust
+        /// This transmutation accepted (by the compiler's implementation of [TransmuteFrom]; the stand-in impls this crate provides on stable only cover identity and archetype transmutations):
+        /// ```rust,ignore
         /// let _ : Foo = 64u32.transmute_into(); // Alchemy Achieved!
         /// //                  ^^^^^^^^^^^^^^ provided by the `TransmuteInto` trait
         /// ```
         /// But this transmutation is rejected:
-        /// ```compile_fail
+        /// ```compile_fail,E0277
+        /// # use rfc::core::convert::transmute::{TransmuteInto, stability::*};
+        /// # #[derive(PromiseTransmutableInto, PromiseTransmutableFrom)]
+        /// # #[repr(C)]
+        /// # pub struct Foo(pub u8, pub u16);
         /// let _ : u32 = Foo(16, 12).transmute_into();
         /// // error[E0277]: the trait bound `u32: TransmuteFrom<Foo, _>` is not satisfied
         /// //   --> src/demo.rs:15:27
@@ -78,6 +80,112 @@ pub mod core {
             /// Reinterprets the bits of a value of one type as another type, safely.
             ///
             /// Use `()` as `Neglect` to omit *no* static checks.
+            ///
+            /// Unlike the methods of [TransmuteFrom] and [TransmuteInto], which cannot be called in a `const` context until `const` trait impls are stabilized, `safe_transmute` may be used to build lookup tables at compile time:
+            /// ```rust,ignore
+            /// const BYTES: [u8; 256] = *include_bytes!("table.bin");
+            /// const TABLE: [u32; 64] = safe_transmute::<_, _, ()>(BYTES);
+            /// ```
+            /// Every primitive type that promises to be stably transmutable may be transmuted, in a `const` context, into bytes and back:
+            /// ```rust,ignore
+            /// use core::num::*;
+            ///
+            /// macro_rules! const_round_trip {
+            ///     ($($T:ty = $value:expr),* $(,)?) => {$(
+            ///         const _: () = {
+            ///             const VALUE: $T = $value;
+            ///             const BYTES: [u8; size_of::<$T>()] = safe_transmute::<_, _, ()>(VALUE);
+            ///             const ROUND_TRIP: $T = safe_transmute::<_, _, ()>(BYTES);
+            ///             assert!(matches!(ROUND_TRIP, VALUE));
+            ///         };
+            ///     )*};
+            /// }
+            ///
+            /// const_round_trip! {
+            ///     () = (),
+            ///     u8 = 0x12, u16 = 0x1234, u32 = 0x1234_5678, u64 = 0x1234_5678_9abc_def0, u128 = u128::MAX, usize = usize::MAX,
+            ///     i8 = -1, i16 = -2, i32 = -3, i64 = -4, i128 = i128::MIN, isize = isize::MIN,
+            ///     bool = true, char = '🌟',
+            /// }
+            ///
+            /// // `f32`, `f64` and the `NonZero` types cannot be matched on, so are compared by their bytes:
+            /// const _: () = assert!(safe_transmute::<f32, u32, ()>(1.0) == 0x3f80_0000);
+            /// const _: () = assert!(safe_transmute::<f64, u64, ()>(1.0) == 0x3ff0_0000_0000_0000);
+            /// const _: () = assert!(safe_transmute::<NonZeroU8, u8, ()>(NonZeroU8::MAX) == u8::MAX);
+            /// const _: () = assert!(safe_transmute::<NonZeroI32, i32, ()>(NonZeroI32::MIN) == i32::MIN);
+            /// const _: () = assert!(safe_transmute::<Option<NonZeroU64>, u64, ()>(None) == 0);
+            /// ```
+            /// On stable, where only the identity transmutation is provided, every primitive that promises to be stably transmutable may be transmuted into itself in a `const` context, neglecting stability:
+            /// ```rust
+            /// use core::{marker::PhantomData, mem::{size_of, ManuallyDrop}, num::*};
+            /// use rfc::core::convert::transmute::{safe_transmute, options::NeglectStability};
+            ///
+            /// const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+            ///     if a.len() != b.len() {
+            ///         return false;
+            ///     }
+            ///     let mut i = 0;
+            ///     while i < a.len() {
+            ///         if a[i] != b[i] {
+            ///             return false;
+            ///         }
+            ///         i += 1;
+            ///     }
+            ///     true
+            /// }
+            ///
+            /// macro_rules! const_identity {
+            ///     ($($T:ty = $value:expr),* $(,)?) => {$(
+            ///         const _: () = {
+            ///             const VALUE: $T = $value;
+            ///             const IDENTITY: $T = safe_transmute::<$T, $T, NeglectStability>(VALUE);
+            ///             // `PartialEq` cannot be used in a `const` context, so the values are compared by their bytes.
+            ///             const EXPECTED: [u8; size_of::<$T>()] = unsafe { core::mem::transmute::<$T, _>(VALUE) };
+            ///             const ACTUAL: [u8; size_of::<$T>()] = unsafe { core::mem::transmute::<$T, _>(IDENTITY) };
+            ///             assert!(bytes_eq(&ACTUAL, &EXPECTED));
+            ///         };
+            ///     )*};
+            /// }
+            ///
+            /// const_identity! {
+            ///     () = (),
+            ///     f32 = 1.0, f64 = -1.0,
+            ///     u8 = 0x12, u16 = 0x1234, u32 = 0x1234_5678, u64 = 0x1234_5678_9abc_def0, u128 = u128::MAX, usize = usize::MAX,
+            ///     i8 = -1, i16 = -2, i32 = -3, i64 = -4, i128 = i128::MIN, isize = isize::MIN,
+            ///     bool = true, char = '🌟',
+            ///     NonZeroU8 = NonZeroU8::MAX, NonZeroU16 = NonZeroU16::MAX, NonZeroU32 = NonZeroU32::MAX,
+            ///     NonZeroU64 = NonZeroU64::MAX, NonZeroU128 = NonZeroU128::MAX, NonZeroUsize = NonZeroUsize::MAX,
+            ///     NonZeroI8 = NonZeroI8::MIN, NonZeroI16 = NonZeroI16::MIN, NonZeroI32 = NonZeroI32::MIN,
+            ///     NonZeroI64 = NonZeroI64::MIN, NonZeroI128 = NonZeroI128::MIN, NonZeroIsize = NonZeroIsize::MIN,
+            ///     Option<NonZeroU8> = None, Option<NonZeroU16> = None, Option<NonZeroU32> = None,
+            ///     Option<NonZeroU64> = None, Option<NonZeroU128> = None, Option<NonZeroUsize> = None,
+            ///     Option<NonZeroI8> = NonZeroI8::new(-8), Option<NonZeroI16> = NonZeroI16::new(-16),
+            ///     Option<NonZeroI32> = NonZeroI32::new(-32), Option<NonZeroI64> = NonZeroI64::new(-64),
+            ///     Option<NonZeroI128> = NonZeroI128::new(-128), Option<NonZeroIsize> = NonZeroIsize::new(-1),
+            ///     PhantomData<u8> = PhantomData,
+            ///     [u16; 3] = [1, 2, 3],
+            ///     Wrapping<u32> = Wrapping(7),
+            ///     ManuallyDrop<u64> = ManuallyDrop::new(9),
+            /// }
+            ///
+            /// // The bytes of pointers cannot be inspected during `const` evaluation, so pointers are compared by what they point to.
+            /// const _: () = {
+            ///     assert!(*safe_transmute::<&u32, &u32, NeglectStability>(&0xF00D) == 0xF00D);
+            ///     assert!(safe_transmute::<*const u8, *const u8, NeglectStability>(core::ptr::null()).is_null());
+            ///     assert!(safe_transmute::<*mut u8, *mut u8, NeglectStability>(core::ptr::null_mut()).is_null());
+            /// };
+            /// ```
+            /// It may also be used from generic `const fn`s:
+            /// ```rust
+            /// use rfc::core::convert::transmute::{TransmuteFrom, safe_transmute};
+            ///
+            /// const fn to_ne_bytes<T>(value: T) -> [u8; 4]
+            /// where
+            ///     [u8; 4]: TransmuteFrom<T>,
+            /// {
+            ///     safe_transmute::<T, [u8; 4], ()>(value)
+            /// }
+            /// ```
             #[inline(always)]
             pub const fn safe_transmute<Src, Dst, Neglect>(src: Src) -> Dst
             where
@@ -89,13 +197,19 @@ pub mod core {
 
             /// Reinterprets the bits of a value of one type as another type, potentially unsafely.
             ///
-            /// The onus is on you to ensure that calling this method is safe.
+            /// The onus is on you to ensure that calling this method is safe. Like [safe_transmute], it may be called in a `const` context.
             #[inline(always)]
             pub const unsafe fn unsafe_transmute<Src, Dst, Neglect>(src: Src) -> Dst
             where
                 Src: TransmuteInto<Dst, Neglect>,
                 Neglect: TransmuteOptions
             {
+                unsafe { transmute_unchecked::<Src, Dst>(src) }
+            }
+
+            // The bit-reinterpretation underlying every transmutation, with no static checks whatsoever.
+            #[inline(always)]
+            const unsafe fn transmute_unchecked<Src, Dst>(src: Src) -> Dst {
                 use core::mem::ManuallyDrop;
 
                 #[repr(C)]
@@ -114,6 +228,8 @@ pub mod core {
             /// The reciprocal of [TransmuteFrom].
             ///
             /// ***This trait is implemented automatically by the compiler for combinations of types where a transmutation is valid.***
+            ///
+            /// **🌟** Its methods cannot be called in a `const` context; e.g., `const TABLE: [u32; 64] = BYTES.transmute_into();` is rejected. The free functions [safe_transmute] and [unsafe_transmute] are `const`, and accept the same bounds.
             pub unsafe trait TransmuteInto<Dst: ?Sized, Neglect = ()>
             where
                 Neglect: TransmuteOptions,
@@ -199,6 +315,353 @@ pub mod core {
             #[doc(hidden)]
             unsafe impl<T> TransmuteFrom<T, NeglectStability> for T {}
 
+            /// Reinterpret the bits of `Self` as a type `Dst`, if they are a valid instance of `Dst`.
+            ///
+            /// The reciprocal of [TryTransmuteFrom].
+            pub unsafe trait TryTransmuteInto<Dst: ?Sized, Neglect = ()>
+            where
+                Neglect: TransmuteOptions,
+            {
+                /// Reinterpret the bits of a value of one type as another type, safely, if they are a valid instance of that type.
+                fn try_transmute_into(self) -> Result<Dst, ValidityError<Self>>
+                where
+                    Self: Sized,
+                    Dst: Sized,
+                    Neglect: SafeTransmuteOptions;
+
+                /// Reinterpret the bits of a value of one type as another type, potentially unsafely, if they are a valid instance of that type.
+                ///
+                /// The onus is on you to ensure that calling this method is safe.
+                unsafe fn unsafe_try_transmute_into(self) -> Result<Dst, ValidityError<Self>>
+                where
+                    Self: Sized,
+                    Dst: Sized,
+                    Neglect: TransmuteOptions;
+            }
+
+            unsafe impl<Src, Dst, Neglect> TryTransmuteInto<Dst, Neglect> for Src
+            where
+                Src: ?Sized,
+                Dst: ?Sized + TryTransmuteFrom<Src, Neglect>,
+                Neglect: TransmuteOptions,
+            {
+                #[inline(always)]
+                fn try_transmute_into(self) -> Result<Dst, ValidityError<Self>>
+                where
+                    Self: Sized,
+                    Dst: Sized,
+                    Neglect: SafeTransmuteOptions,
+                {
+                    Dst::try_transmute_from(self)
+                }
+
+                #[inline(always)]
+                unsafe fn unsafe_try_transmute_into(self) -> Result<Dst, ValidityError<Self>>
+                where
+                    Self: Sized,
+                    Dst: Sized,
+                    Neglect: TransmuteOptions,
+                {
+                    unsafe { Dst::unsafe_try_transmute_from(self) }
+                }
+            }
+
+            /// Reinterpret the bits of `Src` as a type `Self`, if they are a valid instance of `Self`.
+            ///
+            /// The reciprocal of [TryTransmuteInto].
+            ///
+            /// [TransmuteFrom] is only implemented if *every* instance of `Src` is a valid instance of `Self`. [TryTransmuteFrom] is also implemented if only *some* instances of `Src` are valid instances of `Self`, and checks at runtime that the value being transmuted is one of them. It is the safe alternative to neglecting validity with [NeglectValidity] and checking by hand:
+            /// ```rust
+            /// use rfc::core::convert::transmute::{TryTransmuteFrom, TryTransmuteInto};
+            ///
+            /// let _ : bool = 1u8.try_transmute_into().unwrap();
+            ///
+            /// let err = <bool as TryTransmuteFrom<u8>>::try_transmute_from(2u8).unwrap_err();
+            /// assert_eq!(err.into_src(), 2u8);
+            ///
+            /// assert_eq!(<char as TryTransmuteFrom<u32>>::try_transmute_from(0x1F31F).ok(), Some('🌟'));
+            /// assert!(<char as TryTransmuteFrom<u32>>::try_transmute_from(0xD800).is_err());
+            /// ```
+            /// Transmutations which cannot possibly be valid are still rejected statically:
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::TryTransmuteInto;
+            ///
+            /// let _ : Result<u16, _> = 0u8.try_transmute_into();
+            /// // error[E0277]: the trait bound `u16: TryTransmuteFrom<u8, _>` is not satisfied
+            /// ```
+            ///
+            /// ***This trait is implemented automatically by the compiler for combinations of types where a transmutation is valid if its validity check is neglected.*** The compiler also generates [TryTransmuteFrom::is_bit_valid] from the layout of `Self`:
+            ///  - a `bool` is valid if its byte is `0` or `1`,
+            ///  - a `char` is valid if its value is at most `0x10FFFF` and not in `0xD800..=0xDFFF`,
+            ///  - a fieldless enum is valid if its discriminant is one of its variants' discriminants,
+            ///  - a struct is valid if each of its fields is valid,
+            ///  - a slice `[T]` is valid if each whole `T` within the bytes of the source slice is valid, and
+            ///  - any other type is valid if every bit pattern is valid for it.
+            ///
+            /// On stable, `#[derive(PromiseTransmutableFrom)]` stands in for the compiler: it implements [TryTransmuteFrom] from the type's primitive representation and from its bytes for fieldless enums with a `#[repr(<integer>)]`, and from its bytes for structs whose fields are all fallibly transmutable from theirs. A parser can then turn untrusted bytes into an enum without any unsafe code:
+            /// ```rust
+            /// use rfc::core::convert::transmute::{TryTransmuteFrom, stability::*};
+            ///
+            /// #[derive(PromiseTransmutable, Debug, PartialEq)]
+            /// #[repr(u8)]
+            /// pub enum Opcode { Nop = 0x00, Jump = 0x4C }
+            ///
+            /// #[derive(PromiseTransmutable, Debug, PartialEq)]
+            /// #[repr(C)]
+            /// pub struct Instruction { pub op: Opcode, pub short: bool }
+            ///
+            /// assert_eq!(<Opcode as TryTransmuteFrom<u8>>::try_transmute_from(0x4C).ok(), Some(Opcode::Jump));
+            /// assert!(<Opcode as TryTransmuteFrom<u8>>::try_transmute_from(0x4D).is_err());
+            ///
+            /// let instruction = <Instruction as TryTransmuteFrom<[u8; 2]>>::try_transmute_from([0x4C, 1]);
+            /// assert_eq!(instruction.ok(), Some(Instruction { op: Opcode::Jump, short: true }));
+            /// assert!(<Instruction as TryTransmuteFrom<[u8; 2]>>::try_transmute_from([0x4C, 2]).is_err());
+            /// ```
+            pub unsafe trait TryTransmuteFrom<Src: ?Sized, Neglect = ()>
+            where
+                Neglect: TransmuteOptions,
+            {
+                /// Determine whether the bits of `src` are a valid instance of `Self`.
+                fn is_bit_valid(src: &Src) -> bool;
+
+                /// Reinterpret the bits of a value of one type as another type, safely, if they are a valid instance of that type.
+                #[inline(always)]
+                fn try_transmute_from(src: Src) -> Result<Self, ValidityError<Src>>
+                where
+                    Src: Sized,
+                    Self: Sized,
+                    Neglect: SafeTransmuteOptions,
+                {
+                    unsafe { Self::unsafe_try_transmute_from(src) }
+                }
+
+                /// Reinterpret the bits of a value of one type as another type, potentially unsafely, if they are a valid instance of that type.
+                ///
+                /// The onus is on you to ensure that calling this function is safe.
+                #[inline(always)]
+                unsafe fn unsafe_try_transmute_from(src: Src) -> Result<Self, ValidityError<Src>>
+                where
+                    Src: Sized,
+                    Self: Sized,
+                    Neglect: TransmuteOptions,
+                {
+                    const { assert!(core::mem::size_of::<Src>() >= core::mem::size_of::<Self>(), "the destination type is larger than the source type") };
+                    if Self::is_bit_valid(&src) {
+                        Ok(unsafe { transmute_unchecked::<Src, Self>(src) })
+                    } else {
+                        Err(ValidityError { src })
+                    }
+                }
+            }
+
+            /// The error produced when a fallible transmutation fails because its source value is not a valid instance of its destination type.
+            ///
+            /// The rejected source value may be recovered with [ValidityError::into_src].
+            pub struct ValidityError<Src> {
+                src: Src,
+            }
+
+            impl<Src> ValidityError<Src> {
+                /// Produce the source value whose transmutation failed.
+                pub fn into_src(self) -> Src {
+                    self.src
+                }
+            }
+
+            impl<Src> core::fmt::Debug for ValidityError<Src> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_struct("ValidityError").finish()
+                }
+            }
+
+            impl<Src> core::fmt::Display for ValidityError<Src> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str("source value is not a bit-valid instance of the destination type")
+                }
+            }
+
+            // Stand-ins for the impls of `TryTransmuteFrom` that the compiler generates for the primitive types, from their integer representations and from their bytes.
+            macro_rules! try_transmute_from {
+                ($($Dst:ty: $Src:ty => |$src:ident| $is_bit_valid:expr;)*) => {$(
+                    #[doc(hidden)]
+                    unsafe impl TryTransmuteFrom<$Src> for $Dst {
+                        #[inline(always)]
+                        fn is_bit_valid($src: &$Src) -> bool {
+                            $is_bit_valid
+                        }
+                    }
+                )*};
+            }
+
+            macro_rules! all_bit_patterns_valid {
+                ($($T:ty),*) => {$(
+                    try_transmute_from! {
+                        $T: [u8; core::mem::size_of::<$T>()] => |_src| true;
+                    }
+                )*};
+            }
+
+            macro_rules! nonzero {
+                ($($NonZero:ty => $Int:ty),*) => {$(
+                    try_transmute_from! {
+                        $NonZero: $Int => |src| *src != 0;
+                        $NonZero: [u8; core::mem::size_of::<$Int>()] => |src| *src != [0; core::mem::size_of::<$Int>()];
+                    }
+                )*};
+            }
+
+            try_transmute_from! {
+                bool: u8 => |src| *src <= 1;
+                bool: i8 => |src| *src as u8 <= 1;
+                bool: [u8; 1] => |src| src[0] <= 1;
+                char: u32 => |src| char::from_u32(*src).is_some();
+                char: i32 => |src| char::from_u32(*src as u32).is_some();
+                char: [u8; 4] => |src| char::from_u32(u32::from_ne_bytes(*src)).is_some();
+            }
+
+            all_bit_patterns_valid!((), u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+            nonzero! {
+                core::num::NonZeroU8 => u8, core::num::NonZeroU16 => u16, core::num::NonZeroU32 => u32,
+                core::num::NonZeroU64 => u64, core::num::NonZeroU128 => u128, core::num::NonZeroUsize => usize,
+                core::num::NonZeroI8 => i8, core::num::NonZeroI16 => i16, core::num::NonZeroI32 => i32,
+                core::num::NonZeroI64 => i64, core::num::NonZeroI128 => i128, core::num::NonZeroIsize => isize
+            }
+
+            // ...and for slices of them, from slices of bytes. Each whole element's worth of bytes must be valid; the slice casts in `crate::core::slice` reject any leftover bytes before consulting these.
+            macro_rules! slice_from_bytes {
+                ($($T:ty),*) => {$(
+                    #[doc(hidden)]
+                    unsafe impl TryTransmuteFrom<[u8]> for [$T] {
+                        #[inline(always)]
+                        fn is_bit_valid(src: &[u8]) -> bool {
+                            src.chunks_exact(core::mem::size_of::<$T>()).all(|element| {
+                                let mut bytes = [0; core::mem::size_of::<$T>()];
+                                bytes.copy_from_slice(element);
+                                <$T as TryTransmuteFrom<[u8; core::mem::size_of::<$T>()]>>::is_bit_valid(&bytes)
+                            })
+                        }
+                    }
+                )*};
+            }
+
+            slice_from_bytes!(
+                bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+                core::num::NonZeroU8, core::num::NonZeroU16, core::num::NonZeroU32,
+                core::num::NonZeroU64, core::num::NonZeroU128, core::num::NonZeroUsize,
+                core::num::NonZeroI8, core::num::NonZeroI16, core::num::NonZeroI32,
+                core::num::NonZeroI64, core::num::NonZeroI128, core::num::NonZeroIsize
+            );
+
+            /// Assert, at compile time, that `Dst` is [transmutable][TransmuteFrom] from `Src`.
+            ///
+            /// Expands to a `const` item, and so may be placed wherever an item may be; e.g., beside the definitions of the types it concerns:
+            /// ```rust,ignore
+            /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+            /// #[repr(C)]
+            /// pub struct Header {
+            ///     pub tag: u32,
+            ///     pub len: u32,
+            /// }
+            ///
+            /// assert_transmutable!(Header => [u8; 8]);
+            /// assert_transmutable!([u8; 8] => Header);
+            /// assert_transmutable!(&'static [u8; 8] => &'static Header, NeglectAlignment);
+            /// ```
+            /// For instance, every type that is its own [archetype][stability] is transmutable into itself:
+            /// ```rust
+            /// use rfc::core::convert::transmute::{assert_transmutable, options::NeglectStability};
+            ///
+            /// assert_transmutable!(u32 => u32);
+            /// assert_transmutable!([u8; 8] => [u8; 8], NeglectStability);
+            /// ```
+            /// If the transmutation is not possible, compilation fails:
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::assert_transmutable;
+            ///
+            /// assert_transmutable!(u8 => bool);
+            /// // error[E0277]: the trait bound `bool: TransmuteFrom<u8, ()>` is not satisfied
+            /// //   required by a bound in `src_must_be_transmutable_into_dst`
+            /// ```
+            /// To discover *why* a transmutation is not possible, see [explain].
+            #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "transmute_assert", issue = "none"))]
+            #[macro_export]
+            macro_rules! assert_transmutable {
+                ($Src:ty => $Dst:ty $(,)?) => {
+                    $crate::assert_transmutable!($Src => $Dst, ());
+                };
+                ($Src:ty => $Dst:ty, $Neglect:ty $(,)?) => {
+                    const _: () = {
+                        fn src_must_be_transmutable_into_dst<Src: ?Sized, Dst: ?Sized, Neglect>()
+                        where
+                            Neglect: $crate::core::convert::transmute::options::TransmuteOptions,
+                            Dst: $crate::core::convert::transmute::TransmuteFrom<Src, Neglect>,
+                        {}
+                        let _ = src_must_be_transmutable_into_dst::<$Src, $Dst, $Neglect>;
+                    };
+                };
+            }
+
+            #[doc(inline)]
+            pub use crate::assert_transmutable;
+
+            /// Explain why `Dst` is not transmutable from `Src`.
+            ///
+            /// Produces every reason that `Dst` does not implement [`TransmuteFrom<Src, Neglect>`][TransmuteFrom], or nothing if it does. For instance, given:
+            /// ```rust
+            /// use rfc::core::convert::transmute::{explain, Reason, options::*};
+            /// use rfc::layout::{Layout, Modeled};
+            ///
+            /// // #[derive(PromiseTransmutableInto, PromiseTransmutableFrom)]
+            /// #[repr(C)]
+            /// pub struct Foo(pub u8, pub u16);
+            /// //                    ^ there's a padding byte here, between these fields
+            ///
+            /// impl Modeled for Foo {
+            ///     fn layout() -> Layout {
+            ///         Layout::repr_c_struct(&[u8::layout(), u16::layout()]).promise_transmutable()
+            ///     }
+            /// }
+            ///
+            /// #[repr(C)]
+            /// pub struct Bar(pub u8, pub bool, pub u16);
+            ///
+            /// impl Modeled for Bar {
+            ///     fn layout() -> Layout {
+            ///         Layout::repr_c_struct(&[u8::layout(), bool::layout(), u16::layout()])
+            ///     }
+            /// }
+            ///
+            /// // The padding byte of `Foo` prevents it from being transmuted into a `u32`:
+            /// assert_eq!(explain::<Foo, u32, ()>(), [Reason::UninitIntoInit { offset: 1 }]);
+            ///
+            /// // And `Bar` is neither promised to be stable, nor valid for every value of the second byte of a `u32`:
+            /// assert_eq!(explain::<u32, Bar, ()>(), [
+            ///     Reason::NotPromisedFrom,
+            ///     Reason::InvalidValue { offset: 1 },
+            /// ]);
+            /// assert_eq!(explain::<u32, Bar, NeglectStability>(), [Reason::InvalidValue { offset: 1 }]);
+            /// assert_eq!(explain::<u32, Bar, NeglectValidity>(), [Reason::NotPromisedFrom]);
+            /// assert_eq!(explain::<u32, Bar, (NeglectStability, NeglectValidity)>(), []);
+            /// ```
+            ///
+            /// ***This function is implemented by the compiler***, by the same analysis that implements [TransmuteFrom]. Here, it is implemented by the executable model of that analysis, [Layout::explain_transmutable_into][crate::layout::Layout::explain_transmutable_into], for the types that implement [Modeled][crate::layout::Modeled].
+            #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "transmute_explain", issue = "none"))]
+            pub fn explain<Src, Dst, Neglect>() -> Vec<Reason>
+            where
+                Src: crate::layout::Modeled,
+                Dst: crate::layout::Modeled,
+                Neglect: TransmuteOptions,
+            {
+                Src::layout().explain_transmutable_into(&Dst::layout(), Neglect::MODEL)
+            }
+
+            /// A reason that a type is not transmutable into another.
+            ///
+            /// See [explain].
+            #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "transmute_explain", issue = "none"))]
+            pub use crate::layout::Reason;
+
             /// A type `Dst` is [stably][stability] transmutable from `Src` if:
             ///  - `Dst` implements [PromiseTransmutableFrom][trait@PromiseTransmutableFrom], 
             ///  - `Src` implements [PromiseTransmutableInto][trait@PromiseTransmutableInto], and
@@ -214,27 +677,281 @@ pub mod core {
                     >
             {}
 
+            /// Types which may be safely instantiated from any sequence of bytes.
+            ///
+            /// This trait is implemented for every type `T` that is [transmutable][TransmuteFrom] from bytes, neglecting alignment; e.g.:
+            /// ```rust
+            /// use rfc::core::convert::transmute::FromBytes;
+            ///
+            /// assert_eq!(u32::read_from(&[1, 0, 0, 0]), Some(u32::from_le_bytes([1, 0, 0, 0])));
+            /// assert_eq!(u32::read_from(&[1, 0, 0]), None);
+            /// ```
+            /// But not for types with restricted validity:
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::FromBytes;
+            ///
+            /// let _ = <bool as FromBytes>::read_from(&[2]);
+            /// // error[E0277]: the trait bound `bool: FromBytes` is not satisfied
+            /// ```
+            ///
+            /// Since `size_of::<T>()` may not be used as an array length in generic bounds, the bounds of [FromBytes], [FromZeros] and [AsBytes] instead concern a single `T` read from a stream of bytes, `[u8; usize::MAX]` (or, for [AsBytes], a stream of bytes read from a stream of `T`s), in the manner of the [slice casts][crate::core::slice]. On stable, this crate stands in for the compiler's implementations of these bounds for the primitive types.
+            #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "transmute_bytes", issue = "none"))]
+            pub trait FromBytes: Sized {
+                /// Instantiate `Self` from `bytes`, if `bytes` is exactly `size_of::<Self>()` bytes long.
+                fn read_from(bytes: &[u8]) -> Option<Self>;
+            }
+
+            impl<T> FromBytes for T
+            where
+                for<'a> &'a [T; 1]: TransmuteFrom<&'a [u8; usize::MAX], NeglectAlignment>,
+            {
+                #[inline(always)]
+                fn read_from(bytes: &[u8]) -> Option<Self> {
+                    if bytes.len() != core::mem::size_of::<T>() {
+                        return None;
+                    }
+                    // Safe, because `bytes` is the size of `T`, every sequence of bytes is a valid `T`, and the read does not require alignment.
+                    Some(unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const T) })
+                }
+            }
+
+            /// Types for which a sequence of zeroed bytes is a valid instance.
+            ///
+            /// This trait is implemented for every type `T` that is [transmutable][TransmuteFrom] from zeroed bytes, neglecting alignment; e.g.:
+            /// ```rust
+            /// use rfc::core::convert::transmute::FromZeros;
+            ///
+            /// assert_eq!(u32::new_zeroed(), 0);
+            /// assert_eq!(bool::new_zeroed(), false);
+            /// ```
+            /// But not for types whose instances are never zero:
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::FromZeros;
+            ///
+            /// let _ = <core::num::NonZeroU8 as FromZeros>::new_zeroed();
+            /// // error[E0277]: the trait bound `NonZeroU8: FromZeros` is not satisfied
+            /// ```
+            #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "transmute_bytes", issue = "none"))]
+            pub trait FromZeros: Sized {
+                /// Instantiate `Self` from zeroed bytes.
+                fn new_zeroed() -> Self;
+            }
+
+            impl<T> FromZeros for T
+            where
+                for<'a> &'a [T; 1]: TransmuteFrom<&'a [private::Zero; usize::MAX], NeglectAlignment>,
+            {
+                #[inline(always)]
+                fn new_zeroed() -> Self {
+                    // Safe, because a sequence of zeroed bytes is a valid `T`.
+                    unsafe { core::mem::zeroed() }
+                }
+            }
+
+            /// Types which may be safely viewed as a sequence of bytes.
+            ///
+            /// This trait is implemented for every type `T` that is [transmutable][TransmuteFrom] into bytes; i.e., every type without padding, pointers or interior mutability. E.g.:
+            /// ```rust
+            /// use rfc::core::convert::transmute::AsBytes;
+            ///
+            /// assert_eq!(1u32.as_bytes(), &1u32.to_ne_bytes()[..]);
+            /// ```
+            /// But not for types with padding:
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::{AsBytes, stability::{PromiseTransmutableInto, PromiseTransmutableFrom}};
+            ///
+            /// #[derive(PromiseTransmutableInto, PromiseTransmutableFrom)]
+            /// #[repr(C)]
+            /// pub struct Foo(pub u8, pub u16);
+            ///
+            /// let _ = AsBytes::as_bytes(&Foo(16, 12));
+            /// // error[E0277]: the trait bound `Foo: AsBytes` is not satisfied
+            /// ```
+            #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "transmute_bytes", issue = "none"))]
+            pub trait AsBytes: Sized {
+                /// View the bytes of `self`.
+                fn as_bytes(&self) -> &[u8];
+            }
+
+            impl<T> AsBytes for T
+            where
+                for<'a> &'a [u8; usize::MAX]: TransmuteFrom<&'a [T; usize::MAX]>,
+            {
+                #[inline(always)]
+                fn as_bytes(&self) -> &[u8] {
+                    // Safe, because every byte of `T` is an initialized `u8`, and `&T` is no less aligned than `&u8`.
+                    unsafe { core::slice::from_raw_parts(self as *const T as *const u8, core::mem::size_of::<T>()) }
+                }
+            }
+
+            // Stand-ins for the impls of `TransmuteFrom` that the compiler generates for the gadgets underlying `FromBytes`, `FromZeros` and `AsBytes`, for the primitive types.
+            macro_rules! byte_gadgets {
+                (from_bytes: $($T:ty),*) => {$(
+                    #[doc(hidden)]
+                    unsafe impl<'a> TransmuteFrom<&'a [u8; usize::MAX], NeglectAlignment> for &'a [$T; 1] {}
+                )*};
+                (from_zeros: $($T:ty),*) => {$(
+                    #[doc(hidden)]
+                    unsafe impl<'a> TransmuteFrom<&'a [private::Zero; usize::MAX], NeglectAlignment> for &'a [$T; 1] {}
+                )*};
+                (as_bytes: $($T:ty),*) => {$(
+                    #[doc(hidden)]
+                    unsafe impl<'a> TransmuteFrom<&'a [$T; usize::MAX]> for &'a [u8; usize::MAX] {}
+                )*};
+                (as_bytes_mut: $($T:ty),*) => {$(
+                    #[doc(hidden)]
+                    unsafe impl<'a> TransmuteFrom<&'a mut [$T; usize::MAX], NeglectStability> for &'a mut [u8; 1] {}
+                )*};
+            }
+
+            byte_gadgets!(from_bytes: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+            byte_gadgets!(from_zeros:
+                u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char,
+                Option<core::num::NonZeroU8>, Option<core::num::NonZeroU16>, Option<core::num::NonZeroU32>,
+                Option<core::num::NonZeroU64>, Option<core::num::NonZeroU128>, Option<core::num::NonZeroUsize>,
+                Option<core::num::NonZeroI8>, Option<core::num::NonZeroI16>, Option<core::num::NonZeroI32>,
+                Option<core::num::NonZeroI64>, Option<core::num::NonZeroI128>, Option<core::num::NonZeroIsize>
+            );
+
+            byte_gadgets!(as_bytes:
+                u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char,
+                core::num::NonZeroU8, core::num::NonZeroU16, core::num::NonZeroU32,
+                core::num::NonZeroU64, core::num::NonZeroU128, core::num::NonZeroUsize,
+                core::num::NonZeroI8, core::num::NonZeroI16, core::num::NonZeroI32,
+                core::num::NonZeroI64, core::num::NonZeroI128, core::num::NonZeroIsize,
+                Option<core::num::NonZeroU8>, Option<core::num::NonZeroU16>, Option<core::num::NonZeroU32>,
+                Option<core::num::NonZeroU64>, Option<core::num::NonZeroU128>, Option<core::num::NonZeroUsize>,
+                Option<core::num::NonZeroI8>, Option<core::num::NonZeroI16>, Option<core::num::NonZeroI32>,
+                Option<core::num::NonZeroI64>, Option<core::num::NonZeroI128>, Option<core::num::NonZeroIsize>
+            );
+
+            // A `&mut [u8]` view of a `[T]` may be written through, so `T` must be both `FromBytes` and `AsBytes`.
+            byte_gadgets!(as_bytes_mut: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+            mod private {
+                use super::stability::PromiseTransmutableInto;
+
+                // A byte whose only valid value is `0`; used to express the bounds of `FromZeros`.
+                #[derive(Clone, Copy)]
+                #[repr(u8)]
+                pub enum Zero {
+                    Zero = 0,
+                }
+
+                impl PromiseTransmutableInto for Zero {type Archetype = Self;}
+            }
+
             /// Traits for declaring the SemVer stability of a type's layout.
             ///
             /// Since the soundness and safety of a transmutation is affected by the layouts of the source and destination types, changes to those types' layouts may cause code which previously compiled to produce errors. In other words, transmutation causes a type's layout to become part of that type's API for the purposes of SemVer stability.
             /// 
             /// To promise that all transmutations which are currently safe for your type will remain so in the future, simply annotate your type with:
             /// ```rust
+            /// # use rfc::core::convert::transmute::stability::*;
+            /// # pub type Bar = u8;
+            /// # pub type Baz = u16;
             /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
             /// #[repr(C)]
             /// pub struct Foo(pub Bar, pub Baz);
             /// ```
             ///
+            /// Under the RFC, these derives are implemented by the compiler. Here, [`PromiseTransmutableInto`][derive@PromiseTransmutableInto], [`PromiseTransmutableFrom`][derive@PromiseTransmutableFrom] and [`PromiseTransmutable`][derive@PromiseTransmutable] are re-exported from the companion `rfc-derive` crate (in the `derive` directory of this repository), which implements them for `#[repr(C)]` and `#[repr(transparent)]` structs with public fields, `#[repr(C)]` unions, and enums.
+            ///
             /// For more information on stability, [**see here**](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#-when-is-a-transmutation-stable).
+            ///
+            /// ## Validity-Restricted Primitives
+            /// `bool`, `char`, the `NonZero*` integers and `Option<NonZero*>` are promised to be stably transmutable:
+            /// ```rust
+            /// use rfc::core::convert::transmute::TransmuteFrom;
+            /// use core::num::{NonZeroU8, NonZeroU32};
+            ///
+            /// let _ = <bool as TransmuteFrom<bool>>::transmute_from(true);
+            /// let _ = <char as TransmuteFrom<char>>::transmute_from('a');
+            /// let _ = <NonZeroU8 as TransmuteFrom<NonZeroU8>>::transmute_from(NonZeroU8::new(1).unwrap());
+            /// let _ = <Option<NonZeroU32> as TransmuteFrom<Option<NonZeroU32>>>::transmute_from(None);
+            /// ```
+            /// Since these types restrict which bit patterns are valid, they may only be transmuted into types which accept all of their values, and only from types whose values are all valid for them:
+            /// ```rust,ignore
+            /// let _ : u8                = true.transmute_into();
+            /// let _ : u32               = 'a'.transmute_into();
+            /// let _ : u8                = NonZeroU8::new(1).unwrap().transmute_into();
+            /// let _ : Option<NonZeroU8> = NonZeroU8::new(1).unwrap().transmute_into();
+            /// let _ : u32               = Option::<NonZeroU32>::None.transmute_into();
+            /// let _ : Option<NonZeroU8> = 0u8.transmute_into();
+            /// ```
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::TransmuteInto;
+            ///
+            /// let _ : bool = 2u8.transmute_into(); // a `u8` might be neither `0` nor `1`
+            /// ```
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::TransmuteInto;
+            ///
+            /// let _ : char = 0xD800u32.transmute_into(); // a `u32` might be a surrogate or exceed `char::MAX`
+            /// ```
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::TransmuteInto;
+            ///
+            /// let _ : core::num::NonZeroU8 = 0u8.transmute_into(); // a `u8` might be zero
+            /// ```
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::TransmuteInto;
+            ///
+            /// let _ : core::num::NonZeroU8 = Option::<core::num::NonZeroU8>::None.transmute_into(); // `None` is zero
+            /// ```
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::TransmuteInto;
+            ///
+            /// let _ : bool = core::num::NonZeroU8::new(2).unwrap().transmute_into(); // a `NonZeroU8` might exceed `1`
+            /// ```
+            ///
+            /// ## Wrappers
+            /// `Wrapping<T>`, `Cell<T>`, `UnsafeCell<T>`, `ManuallyDrop<T>` and `MaybeUninit<T>` are laid out exactly as `T` is, and so are promised to be stably transmutable whenever `T` is:
+            /// ```rust
+            /// use rfc::core::convert::transmute::TransmuteFrom;
+            /// use core::{cell::{Cell, UnsafeCell}, mem::{ManuallyDrop, MaybeUninit}, num::{NonZeroU8, Wrapping}};
+            ///
+            /// let _ = <Wrapping<u32> as TransmuteFrom<Wrapping<u32>>>::transmute_from(Wrapping(1));
+            /// let _ = <Cell<char> as TransmuteFrom<Cell<char>>>::transmute_from(Cell::new('a'));
+            /// let _ = <UnsafeCell<i64> as TransmuteFrom<UnsafeCell<i64>>>::transmute_from(UnsafeCell::new(-1));
+            /// let _ = <ManuallyDrop<bool> as TransmuteFrom<ManuallyDrop<bool>>>::transmute_from(ManuallyDrop::new(true));
+            /// let _ = <MaybeUninit<NonZeroU8> as TransmuteFrom<MaybeUninit<NonZeroU8>>>::transmute_from(MaybeUninit::uninit());
+            ///
+            /// // Wrappers may be nested:
+            /// let _ = <Wrapping<ManuallyDrop<u8>> as TransmuteFrom<Wrapping<ManuallyDrop<u8>>>>::transmute_from(Wrapping(ManuallyDrop::new(1)));
+            ///
+            /// // As may arrays and references, which forward their promises likewise:
+            /// let _ = <&[Wrapping<u16>; 2] as TransmuteFrom<&[Wrapping<u16>; 2]>>::transmute_from(&[Wrapping(1), Wrapping(2)]);
+            /// ```
+            /// But a wrapper makes no promises about a type that does not make them itself:
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::TransmuteFrom;
+            /// use core::num::Wrapping;
+            ///
+            /// #[repr(C)]
+            /// pub struct Foo(pub u8);
+            ///
+            /// let _ = <Wrapping<Foo> as TransmuteFrom<Wrapping<Foo>>>::transmute_from(Wrapping(Foo(1)));
+            /// ```
+            /// Nor do tuples make any promises, since their layout is unspecified:
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::TransmuteFrom;
+            ///
+            /// let _ = <(u8, u16) as TransmuteFrom<(u8, u16)>>::transmute_from((1, 2));
+            /// ```
             pub mod stability {
 
                 use super::{TransmuteFrom, TransmuteInto, options::NeglectStability};
 
                 /// Promise that a type may be stably transmuted *into* other types.
                 ///
-                /// To promise that all safe transmutations from your type into other `PromiseTransmutableFrom` types will remain safe in the future, simply annotate your type with `#[derive(PromiseTransmutableFrom)]`; e.g.:
+                /// To promise that all safe transmutations from your type into other `PromiseTransmutableFrom` types will remain safe in the future, simply annotate your type with `#[derive(PromiseTransmutableInto)]`; e.g.:
                 /// ```rust
-                /// #[derive(PromiseTransmutableFrom)]
+                /// # use rfc::core::convert::transmute::stability::*;
+                /// # pub type Bar = u8;
+                /// # pub type Baz = u16;
+                /// #[derive(PromiseTransmutableInto)]
                 /// #[repr(C)]
                 /// pub struct Foo(pub Bar, pub Baz);
                 /// ```
@@ -252,6 +969,9 @@ pub mod core {
                 /// 
                 /// For instance, this:
                 /// ```rust
+                /// # use rfc::core::convert::transmute::stability::*;
+                /// # pub type Bar = u8;
+                /// # pub type Baz = u16;
                 /// #[derive(PromiseTransmutableFrom)]
                 /// #[repr(C)]
                 /// pub struct Foo(pub Bar, pub Baz);
@@ -265,95 +985,578 @@ pub mod core {
                     type Archetype: TransmuteInto<Self, NeglectStability>;
                 }
 
-                #[doc(hidden)]
-                mod macros {
-                    use super::*;
-
-                    /// Derive macro generating an impl of the trait [PromiseTransmutableFrom][trait@PromiseTransmutableFrom].
-                    ///
-                    /// To promise that all safe transmutations from your type into other `PromiseTransmutableFrom` types will remain safe in the future, simply annotate your type with `#[derive(PromiseTransmutableFrom)]`.
-                    /// 
-                    /// For instance, this:
-                    /// ```rust
-                    /// #[derive(PromiseTransmutableFrom)]
-                    /// #[repr(C)]
-                    /// pub struct Foo(pub Bar, pub Baz);
-                    /// ```
-                    /// will expand to this:
-                    /// ```rust
-                    /// /// Generated `PromiseTransmutableInto` for `Foo`
-                    /// const _: () = {
-                    ///     use core::convert::transmute::stability::PromiseTransmutableInto;
-                    /// 
-                    ///     #[repr(C)]
-                    ///     pub struct TransmutableIntoArchetype(
-                    ///         pub <Bar as PromiseTransmutableInto>::Archetype,
-                    ///         pub <Baz as PromiseTransmutableInto>::Archetype,
-                    ///     );
-                    /// 
-                    ///     impl PromiseTransmutableInto for TransmutableIntoArchetype { type Archetype = Self };
-                    /// 
-                    ///     impl PromiseTransmutableInto for Foo {
-                    ///         type Archetype = TransmutableIntoArchetype;
-                    ///     }
-                    /// };
-                    /// ```
-                    pub macro PromiseTransmutableInto($item:item) {
-                        /* compiler built-in */
-                    }
+                /// **🌟** Runtime reflection of the layout of a type.
+                ///
+                /// Implemented for every type in this crate that promises to be stably transmutable, and generated for your types by [`#[derive(PromiseTransmutableInto)]`][macro@PromiseTransmutableInto] (which, for structs, reports each field). The produced [TypeLayout] may be printed, or serialized as JSON, for tooling that must track the layouts of on-disk or on-the-wire types across releases:
+                /// ```rust
+                /// use rfc::core::convert::transmute::stability::*;
+                ///
+                /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+                /// #[repr(C)]
+                /// pub struct Record {
+                ///     pub tag: u8,
+                ///     pub len: u32,
+                /// }
+                ///
+                /// let layout = Record::layout_info();
+                /// assert_eq!(layout.size, 8);
+                /// assert_eq!(layout.fields[1].offset, 4);
+                /// assert_eq!(layout.padding(), vec![1..4]);
+                ///
+                /// println!("{}", layout);
+                /// // Record: size 8, align 4
+                /// //   .tag @ 0: u8: size 1, align 1
+                /// //   .len @ 4: u32: size 4, align 4
+                /// //   padding: 1..4
+                /// //   archetype: TransmutableIntoArchetype: size 8, align 4
+                /// //     ...
+                /// ```
+                /// The reported size and alignment of every type agrees with [size_of] and [align_of]:
+                /// ```rust
+                /// use core::{cell::*, marker::PhantomData, mem::*, num::*};
+                /// use rfc::core::convert::transmute::{byteorder::*, stability::LayoutInfo};
+                ///
+                /// macro_rules! check {
+                ///     ($($T:ty),*) => {$(
+                ///         let layout = <$T as LayoutInfo>::layout_info();
+                ///         assert_eq!(layout.size, size_of::<$T>(), "{}", stringify!($T));
+                ///         assert_eq!(layout.align, align_of::<$T>(), "{}", stringify!($T));
+                ///     )*}
+                /// }
+                ///
+                /// check!((), f32, f64, bool, char);
+                /// check!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+                /// check!(NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize);
+                /// check!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+                /// check!(Option<NonZeroI8>, Option<NonZeroI16>, Option<NonZeroI32>, Option<NonZeroI64>, Option<NonZeroI128>, Option<NonZeroIsize>);
+                /// check!(Option<NonZeroU8>, Option<NonZeroU16>, Option<NonZeroU32>, Option<NonZeroU64>, Option<NonZeroU128>, Option<NonZeroUsize>);
+                /// check!(PhantomData<str>, [u16; 3], [(); 7], *const u8, *mut [u8], &'static u32, &'static mut str);
+                /// check!(Wrapping<u8>, Cell<u16>, UnsafeCell<u32>, ManuallyDrop<u64>, MaybeUninit<u128>);
+                /// check!((u8,), (u8, u16), (u8, u32, u16), (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u64));
+                /// check!(U16<BigEndian>, U32<LittleEndian>, I64<NetworkEndian>, I128<NativeEndian>, Box<u32>, Box<[u8]>);
+                /// ```
+                #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "layout_info", issue = "none"))]
+                pub trait LayoutInfo {
+                    /// Describe the layout of `Self`.
+                    fn layout_info() -> TypeLayout;
+                }
 
-                    /// Derive macro generating an impl of the trait [PromiseTransmutableFrom][trait@PromiseTransmutableFrom].
+                /// **🌟** The layout of a type, as reported by [LayoutInfo].
+                #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "layout_info", issue = "none"))]
+                #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+                pub struct TypeLayout {
+                    /// The name of the type, as given by [type_name][core::any::type_name].
+                    pub name: String,
+                    /// The size of the type, in bytes.
+                    pub size: usize,
+                    /// The minimum alignment of the type, in bytes.
+                    pub align: usize,
+                    /// The fields of the type, in declaration order. Types without fields (e.g., the primitive types and pointers) are opaque; the elements of an array are reported as fields named `[0]`, `[1]`, etc.
+                    pub fields: Vec<FieldLayout>,
+                    /// The layout of the type's [Archetype][PromiseTransmutableInto::Archetype], if it is not the type itself.
                     ///
-                    /// To promise that all transmutations of any `PromiseTransmutableInto` type into your type that are currently safe will remain so in the future, simply annotate your type with `#[derive(PromiseTransmutableFrom)]`.
-                    /// 
-                    /// For instance, this:
-                    /// ```rust
-                    /// #[derive(PromiseTransmutableFrom)]
-                    /// #[repr(C)]
-                    /// pub struct Foo(pub Bar, pub Baz);
-                    /// ```
-                    /// will expand to this:
-                    /// ```rust
-                    /// /// Generated `PromiseTransmutableFrom` for `Foo`
-                    /// const _: () = {
-                    ///     use core::convert::transmute::stability::PromiseTransmutableFrom;
-                    /// 
-                    ///     #[repr(C)]
-                    ///     pub struct TransmutableFromArchetype(
-                    ///         pub <Bar as PromiseTransmutableFrom>::Archetype,
-                    ///         pub <Baz as PromiseTransmutableFrom>::Archetype,
-                    ///     );
-                    /// 
-                    ///     impl PromiseTransmutableFrom for TransmutableFromArchetype { type Archetype = Self };
-                    /// 
-                    ///     impl PromiseTransmutableFrom for Foo {
-                    ///         type Archetype = TransmutableFromArchetype;
-                    ///     }
-                    /// };
-                    /// ```
-                    pub macro PromiseTransmutableFrom($item:item) {
-                        /* compiler built-in */
+                    /// The archetype of an array or wrapper type merely replaces its fields with their archetypes; for these types, this is `None`, and the archetype of each field is reported on that field instead.
+                    pub archetype: Option<Box<TypeLayout>>,
+                }
+
+                /// **🌟** A field of a [TypeLayout].
+                #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "layout_info", issue = "none"))]
+                #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+                pub struct FieldLayout {
+                    /// The name of the field; for tuple structs and tuples, its index.
+                    pub name: String,
+                    /// The offset of the field from the start of its type, in bytes.
+                    pub offset: usize,
+                    /// The layout of the field's type.
+                    pub layout: TypeLayout,
+                }
+
+                impl TypeLayout {
+                    /// The layout of `T`, without fields or an archetype.
+                    pub fn of<T>() -> Self {
+                        TypeLayout {
+                            name: core::any::type_name::<T>().to_owned(),
+                            size: core::mem::size_of::<T>(),
+                            align: core::mem::align_of::<T>(),
+                            fields: Vec::new(),
+                            archetype: None,
+                        }
                     }
 
-                    /// Derive macro generating impls of *both* [PromiseTransmutableFrom][trait@PromiseTransmutableFrom] and [PromiseTransmutableInto][trait@PromiseTransmutableInto].
-                    ///
-                    /// This is just a shorthand for deriving both [PromiseTransmutableFrom!] and [PromiseTransmutableInto!].
-                    ///
-                    /// For more information on this extension, [**see here**](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#extension-promisetransmutable-shorthand).
-                    #[cfg_attr(doc, unstable(feature = "stability_shorthand", issue = "none"))]
-                    pub macro PromiseTransmutable($item:item) {
-                        /* compiler built-in */
+                    /// Append a field named `name`, at `offset`, of type `F`.
+                    pub fn field<F: LayoutInfo>(mut self, name: impl Into<String>, offset: usize) -> Self {
+                        self.fields.push(FieldLayout { name: name.into(), offset, layout: F::layout_info() });
+                        self
+                    }
+
+                    /// Record the archetype of `T`, unless it is `T` itself.
+                    pub fn archetype<T>(mut self) -> Self
+                    where
+                        T: PromiseTransmutableInto + 'static,
+                        T::Archetype: LayoutInfo + 'static,
+                    {
+                        if core::any::TypeId::of::<T>() != core::any::TypeId::of::<T::Archetype>() {
+                            self.archetype = Some(Box::new(T::Archetype::layout_info()));
+                        }
+                        self
+                    }
+
+                    /// The ranges of bytes that are padding: bytes not covered by any field, or covered only by padding of fields. Types without fields have no padding.
+                    pub fn padding(&self) -> Vec<core::ops::Range<usize>> {
+                        if self.fields.is_empty() {
+                            return Vec::new();
+                        }
+                        let mut initialized = vec![false; self.size];
+                        for field in &self.fields {
+                            let padding = field.layout.padding();
+                            for byte in 0..field.layout.size {
+                                if !padding.iter().any(|range| range.contains(&byte)) {
+                                    initialized[field.offset + byte] = true;
+                                }
+                            }
+                        }
+                        let mut ranges: Vec<core::ops::Range<usize>> = Vec::new();
+                        for (offset, _) in initialized.iter().enumerate().filter(|(_, init)| !**init) {
+                            match ranges.last_mut() {
+                                Some(range) if range.end == offset => range.end += 1,
+                                _ => ranges.push(offset..offset + 1),
+                            }
+                        }
+                        ranges
+                    }
+
+                    /// Serialize this layout as a JSON object, with the members `name`, `size`, `align`, `fields` (an array of objects with the members `name`, `offset` and `layout`), `padding` (an array of `[start, end]` pairs) and `archetype` (a layout, or `null`).
+                    pub fn to_json(&self) -> String {
+                        fn string(s: &str) -> String {
+                            let mut out = String::from("\"");
+                            for c in s.chars() {
+                                match c {
+                                    '"' => out.push_str("\\\""),
+                                    '\\' => out.push_str("\\\\"),
+                                    c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                                    c => out.push(c),
+                                }
+                            }
+                            out.push('"');
+                            out
+                        }
+
+                        let fields: Vec<String> = self.fields.iter().map(|field| format!(
+                            "{{\"name\":{},\"offset\":{},\"layout\":{}}}",
+                            string(&field.name), field.offset, field.layout.to_json()
+                        )).collect();
+                        let padding: Vec<String> = self.padding().iter().map(|range| format!("[{},{}]", range.start, range.end)).collect();
+                        format!(
+                            "{{\"name\":{},\"size\":{},\"align\":{},\"fields\":[{}],\"padding\":[{}],\"archetype\":{}}}",
+                            string(&self.name),
+                            self.size,
+                            self.align,
+                            fields.join(","),
+                            padding.join(","),
+                            self.archetype.as_ref().map_or_else(|| String::from("null"), |archetype| archetype.to_json()),
+                        )
+                    }
+
+                    fn fmt_indented(&self, f: &mut core::fmt::Formatter<'_>, depth: usize) -> core::fmt::Result {
+                        writeln!(f, "{}: size {}, align {}", self.name, self.size, self.align)?;
+                        let indent = "  ".repeat(depth + 1);
+                        for field in &self.fields {
+                            write!(f, "{}.{} @ {}: ", indent, field.name, field.offset)?;
+                            field.layout.fmt_indented(f, depth + 1)?;
+                        }
+                        let padding = self.padding();
+                        if !padding.is_empty() {
+                            let ranges: Vec<String> = padding.iter().map(|range| format!("{}..{}", range.start, range.end)).collect();
+                            writeln!(f, "{}padding: {}", indent, ranges.join(", "))?;
+                        }
+                        if let Some(archetype) = &self.archetype {
+                            write!(f, "{}archetype: ", indent)?;
+                            archetype.fmt_indented(f, depth + 1)?;
+                        }
+                        Ok(())
+                    }
+                }
+
+                impl core::fmt::Display for TypeLayout {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        self.fmt_indented(f, 0)
                     }
                 }
 
                 #[doc(inline)]
-                pub use macros::{
+                pub use rfc_derive::{
                     PromiseTransmutableInto,
                     PromiseTransmutableFrom,
                     PromiseTransmutable,
                 };
 
+                /// **🌟** A declarative alternative to [`#[derive(PromiseTransmutable)]`][macro@PromiseTransmutable], for structs, enums and unions.
+                ///
+                /// Wrap a type definition in this macro to implement [PromiseTransmutableInto][trait@PromiseTransmutableInto], [PromiseTransmutableFrom][trait@PromiseTransmutableFrom] and [LayoutInfo][trait@LayoutInfo] for it. The definition's first attribute must be its `#[repr(...)]`, and it may not have generic parameters. A tuple struct may have at most 16 fields:
+                /// ```compile_fail
+                /// use rfc::core::convert::transmute::stability::promise_transmutable;
+                ///
+                /// promise_transmutable! {
+                ///     #[repr(C)]
+                ///     pub struct Wide(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+                /// }
+                /// // error: `promise_transmutable!` supports tuple structs of at most 16 fields; use `#[derive(PromiseTransmutable)]` instead
+                /// ```
+                ///
+                /// ## Archetypes
+                /// The archetypes generated by the stability derives (and by this macro) have exactly the shape of the annotated type, with the type of every field replaced by that type's archetype, and the same `repr`:
+                ///  - A **struct**'s archetype is a struct with the same fields, in the same order.
+                ///  - A **union**'s archetype is a union with the same fields, each wrapped in [ManuallyDrop][core::mem::ManuallyDrop] (which does not affect its layout), since the archetypes of `Copy` types need not be `Copy`.
+                ///  - An **enum**'s archetype is an enum with the same variants, in the same order, with the same discriminants, and with the same fields in each variant. The layout of an enum with fields is only defined for the primitive representations (e.g., `#[repr(u8)]`) and `#[repr(C)]`, as a union of `#[repr(C)]` structs each beginning with the discriminant; the archetype is laid out accordingly.
+                ///
+                /// The [PromiseTransmutableInto][trait@PromiseTransmutableInto] archetype is built from the fields' [PromiseTransmutableInto::Archetype]s, and the [PromiseTransmutableFrom][trait@PromiseTransmutableFrom] archetype from their [PromiseTransmutableFrom::Archetype]s. Consequently, for every field of every variant, the promise made about a type is exactly the promise made by the type of that field, and no more.
+                ///
+                /// ## Example
+                /// Since an archetype depends only on the archetypes of its fields, the types of those fields may change without breaking downstream transmutations, so long as their archetypes do not. For instance, a crate's `Payload` type may have private contents that change between releases:
+                /// ```rust
+                /// use rfc::core::convert::transmute::stability::{LayoutInfo, PromiseTransmutableInto, TypeLayout};
+                ///
+                /// mod v1 {
+                ///     use rfc::core::convert::transmute::{TransmuteFrom, options::NeglectStability, stability::*};
+                ///
+                ///     #[repr(C)]
+                ///     pub struct Payload(u16);
+                ///
+                ///     // Promises only that a `Payload` is a `u16`-like value, which may be made from a non-zero `u16`.
+                ///     impl PromiseTransmutableInto for Payload { type Archetype = u16; }
+                ///     impl PromiseTransmutableFrom for Payload { type Archetype = core::num::NonZeroU16; }
+                ///
+                ///     // Stand-ins for the impls that the compiler generates.
+                ///     unsafe impl TransmuteFrom<Payload, NeglectStability> for u16 {}
+                ///     unsafe impl TransmuteFrom<core::num::NonZeroU16, NeglectStability> for Payload {}
+                ///
+                ///     promise_transmutable! {
+                ///         #[repr(u8)]
+                ///         pub enum Message {
+                ///             Data(Payload) = 1,
+                ///             Ack { seq: u16 } = 2,
+                ///         }
+                ///     }
+                /// }
+                ///
+                /// mod v2 {
+                ///     use rfc::core::convert::transmute::{TransmuteFrom, options::NeglectStability, stability::*};
+                ///
+                ///     // The contents of `Payload` changed, but its archetype did not.
+                ///     #[repr(C)]
+                ///     pub struct Payload(core::num::NonZeroU16);
+                ///
+                ///     impl PromiseTransmutableInto for Payload { type Archetype = u16; }
+                ///     impl PromiseTransmutableFrom for Payload { type Archetype = core::num::NonZeroU16; }
+                ///
+                ///     unsafe impl TransmuteFrom<Payload, NeglectStability> for u16 {}
+                ///     unsafe impl TransmuteFrom<core::num::NonZeroU16, NeglectStability> for Payload {}
+                ///
+                ///     promise_transmutable! {
+                ///         #[repr(u8)]
+                ///         pub enum Message {
+                ///             Data(Payload) = 1,
+                ///             Ack { seq: u16 } = 2,
+                ///         }
+                ///     }
+                /// }
+                ///
+                /// // Downstream code that relies only on what a `Message` promises.
+                /// fn archetype_of<M>() -> TypeLayout
+                /// where
+                ///     M: PromiseTransmutableInto,
+                ///     M::Archetype: LayoutInfo,
+                /// {
+                ///     <M::Archetype as LayoutInfo>::layout_info()
+                /// }
+                ///
+                /// // Both releases promise an archetype of the same layout, so this compiles, and agrees, against either.
+                /// let (v1, v2) = (archetype_of::<v1::Message>(), archetype_of::<v2::Message>());
+                /// assert_eq!((v1.size, v1.align), (v2.size, v2.align));
+                /// ```
+                /// With the compiler's implementation of [TransmuteFrom], so does a downstream crate's view of a `Message`:
+                /// ```rust,ignore
+                /// #[derive(PromiseTransmutableFrom)]
+                /// #[repr(C)]
+                /// pub struct Raw {
+                ///     pub tag: u8,
+                ///     pub _padding: MaybeUninit<u8>,
+                ///     pub value: u16,
+                /// }
+                ///
+                /// let raw: Raw = v1::Message::Ack { seq: 7 }.transmute_into();
+                /// let raw: Raw = v2::Message::Ack { seq: 7 }.transmute_into();
+                /// ```
+                /// But downstream code may not depend on the private contents of a `Payload`. Every `u16` is a valid `v1::Payload`, but `0` is not a valid `v2::Payload`, so the archetype of `Payload` does not promise that one may be made from a `u16`:
+                /// ```compile_fail,E0277
+                /// use rfc::core::convert::transmute::{TransmuteFrom, options::NeglectStability, stability::*};
+                ///
+                /// #[repr(C)]
+                /// pub struct Payload(u16);
+                ///
+                /// impl PromiseTransmutableInto for Payload { type Archetype = u16; }
+                /// impl PromiseTransmutableFrom for Payload { type Archetype = core::num::NonZeroU16; }
+                ///
+                /// unsafe impl TransmuteFrom<Payload, NeglectStability> for u16 {}
+                /// unsafe impl TransmuteFrom<core::num::NonZeroU16, NeglectStability> for Payload {}
+                ///
+                /// // Sound against the layout of this release of `Payload`, but not promised by it.
+                /// let payload: Payload = TransmuteFrom::<_>::transmute_from(0u16);
+                /// ```
+                /// Unions are supported in the same way:
+                /// ```rust,ignore
+                /// promise_transmutable! {
+                ///     #[repr(C)]
+                ///     pub union Word {
+                ///         pub bytes: [u8; 4],
+                ///         pub value: u32,
+                ///     }
+                /// }
+                ///
+                /// let _: u32 = Word { value: 42 }.transmute_into();
+                /// ```
+                #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "stability_shorthand", issue = "none"))]
+                #[macro_export]
+                macro_rules! promise_transmutable {
+                    (
+                        #[repr($($repr:tt)*)]
+                        $(#[$attr:meta])*
+                        $vis:vis struct $Name:ident {
+                            $($(#[$fattr:meta])* $fvis:vis $field:ident : $fty:ty),* $(,)?
+                        }
+                    ) => {
+                        #[repr($($repr)*)]
+                        $(#[$attr])*
+                        $vis struct $Name {
+                            $($(#[$fattr])* $fvis $field : $fty),*
+                        }
+
+                        const _: () = {
+                            #[repr($($repr)*)]
+                            pub struct TransmutableIntoArchetype {
+                                $(pub $field : <$fty as $crate::core::convert::transmute::stability::PromiseTransmutableInto>::Archetype),*
+                            }
+
+                            #[repr($($repr)*)]
+                            pub struct TransmutableFromArchetype {
+                                $(pub $field : <$fty as $crate::core::convert::transmute::stability::PromiseTransmutableFrom>::Archetype),*
+                            }
+
+                            impl $crate::core::convert::transmute::stability::LayoutInfo for TransmutableIntoArchetype {
+                                fn layout_info() -> $crate::core::convert::transmute::stability::TypeLayout {
+                                    $crate::core::convert::transmute::stability::TypeLayout::of::<Self>()
+                                        $(.field::<<$fty as $crate::core::convert::transmute::stability::PromiseTransmutableInto>::Archetype>(stringify!($field), ::core::mem::offset_of!(Self, $field)))*
+                                }
+                            }
+
+                            impl $crate::core::convert::transmute::stability::LayoutInfo for $Name {
+                                fn layout_info() -> $crate::core::convert::transmute::stability::TypeLayout {
+                                    $crate::core::convert::transmute::stability::TypeLayout::of::<Self>()
+                                        $(.field::<$fty>(stringify!($field), ::core::mem::offset_of!(Self, $field)))*
+                                        .archetype::<Self>()
+                                }
+                            }
+
+                            $crate::promise_transmutable!(@impls $Name);
+                        };
+                    };
+                    (
+                        #[repr($($repr:tt)*)]
+                        $(#[$attr:meta])*
+                        $vis:vis struct $Name:ident (
+                            $($(#[$fattr:meta])* $fvis:vis $fty:ty),* $(,)?
+                        );
+                    ) => {
+                        #[repr($($repr)*)]
+                        $(#[$attr])*
+                        $vis struct $Name (
+                            $($(#[$fattr])* $fvis $fty),*
+                        );
+
+                        const _: () = {
+                            #[repr($($repr)*)]
+                            pub struct TransmutableIntoArchetype (
+                                $(pub <$fty as $crate::core::convert::transmute::stability::PromiseTransmutableInto>::Archetype),*
+                            );
+
+                            #[repr($($repr)*)]
+                            pub struct TransmutableFromArchetype (
+                                $(pub <$fty as $crate::core::convert::transmute::stability::PromiseTransmutableFrom>::Archetype),*
+                            );
+
+                            impl $crate::core::convert::transmute::stability::LayoutInfo for TransmutableIntoArchetype {
+                                fn layout_info() -> $crate::core::convert::transmute::stability::TypeLayout {
+                                    $crate::promise_transmutable!(@tuple_fields
+                                        $crate::core::convert::transmute::stability::TypeLayout::of::<Self>();
+                                        [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15];
+                                        $(<$fty as $crate::core::convert::transmute::stability::PromiseTransmutableInto>::Archetype),*
+                                    )
+                                }
+                            }
+
+                            impl $crate::core::convert::transmute::stability::LayoutInfo for $Name {
+                                fn layout_info() -> $crate::core::convert::transmute::stability::TypeLayout {
+                                    $crate::promise_transmutable!(@tuple_fields
+                                        $crate::core::convert::transmute::stability::TypeLayout::of::<Self>();
+                                        [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15];
+                                        $($fty),*
+                                    ).archetype::<Self>()
+                                }
+                            }
+
+                            $crate::promise_transmutable!(@impls $Name);
+                        };
+                    };
+                    (
+                        #[repr($($repr:tt)*)]
+                        $(#[$attr:meta])*
+                        $vis:vis union $Name:ident {
+                            $($(#[$fattr:meta])* $fvis:vis $field:ident : $fty:ty),* $(,)?
+                        }
+                    ) => {
+                        #[repr($($repr)*)]
+                        $(#[$attr])*
+                        $vis union $Name {
+                            $($(#[$fattr])* $fvis $field : $fty),*
+                        }
+
+                        const _: () = {
+                            #[repr($($repr)*)]
+                            pub union TransmutableIntoArchetype {
+                                $(pub $field : ::core::mem::ManuallyDrop<<$fty as $crate::core::convert::transmute::stability::PromiseTransmutableInto>::Archetype>),*
+                            }
+
+                            #[repr($($repr)*)]
+                            pub union TransmutableFromArchetype {
+                                $(pub $field : ::core::mem::ManuallyDrop<<$fty as $crate::core::convert::transmute::stability::PromiseTransmutableFrom>::Archetype>),*
+                            }
+
+                            impl $crate::core::convert::transmute::stability::LayoutInfo for TransmutableIntoArchetype {
+                                fn layout_info() -> $crate::core::convert::transmute::stability::TypeLayout {
+                                    $crate::core::convert::transmute::stability::TypeLayout::of::<Self>()
+                                        $(.field::<<$fty as $crate::core::convert::transmute::stability::PromiseTransmutableInto>::Archetype>(stringify!($field), 0))*
+                                }
+                            }
+
+                            impl $crate::core::convert::transmute::stability::LayoutInfo for $Name {
+                                fn layout_info() -> $crate::core::convert::transmute::stability::TypeLayout {
+                                    $crate::core::convert::transmute::stability::TypeLayout::of::<Self>()
+                                        $(.field::<$fty>(stringify!($field), 0))*
+                                        .archetype::<Self>()
+                                }
+                            }
+
+                            $crate::promise_transmutable!(@impls $Name);
+                        };
+                    };
+                    (
+                        #[repr($($repr:tt)*)]
+                        $(#[$attr:meta])*
+                        $vis:vis enum $Name:ident {
+                            $(
+                                $(#[$vattr:meta])*
+                                $Variant:ident
+                                $(( $($tty:ty),* $(,)? ))?
+                                $({ $($vfield:ident : $vty:ty),* $(,)? })?
+                                $(= $disc:expr)?
+                            ),* $(,)?
+                        }
+                    ) => {
+                        #[repr($($repr)*)]
+                        $(#[$attr])*
+                        $vis enum $Name {
+                            $(
+                                $(#[$vattr])*
+                                $Variant
+                                $(( $($tty),* ))?
+                                $({ $($vfield : $vty),* })?
+                                $(= $disc)?
+                            ),*
+                        }
+
+                        const _: () = {
+                            #[repr($($repr)*)]
+                            #[allow(dead_code)]
+                            pub enum TransmutableIntoArchetype {
+                                $(
+                                    $Variant
+                                    $(( $(<$tty as $crate::core::convert::transmute::stability::PromiseTransmutableInto>::Archetype),* ))?
+                                    $({ $($vfield : <$vty as $crate::core::convert::transmute::stability::PromiseTransmutableInto>::Archetype),* })?
+                                    $(= $disc)?
+                                ),*
+                            }
+
+                            #[repr($($repr)*)]
+                            #[allow(dead_code)]
+                            pub enum TransmutableFromArchetype {
+                                $(
+                                    $Variant
+                                    $(( $(<$tty as $crate::core::convert::transmute::stability::PromiseTransmutableFrom>::Archetype),* ))?
+                                    $({ $($vfield : <$vty as $crate::core::convert::transmute::stability::PromiseTransmutableFrom>::Archetype),* })?
+                                    $(= $disc)?
+                                ),*
+                            }
+
+                            // The variants of an enum overlap, and are distinguished by their discriminant; an enum's layout is therefore reported without fields.
+                            impl $crate::core::convert::transmute::stability::LayoutInfo for TransmutableIntoArchetype {
+                                fn layout_info() -> $crate::core::convert::transmute::stability::TypeLayout {
+                                    $crate::core::convert::transmute::stability::TypeLayout::of::<Self>()
+                                }
+                            }
+
+                            impl $crate::core::convert::transmute::stability::LayoutInfo for $Name {
+                                fn layout_info() -> $crate::core::convert::transmute::stability::TypeLayout {
+                                    $crate::core::convert::transmute::stability::TypeLayout::of::<Self>()
+                                        .archetype::<Self>()
+                                }
+                            }
+
+                            $crate::promise_transmutable!(@impls $Name);
+                        };
+                    };
+                    (@impls $Name:ident) => {
+                        // Stand-ins for the impls of `TransmuteFrom` that the compiler generates between a type and its archetypes, which have exactly its layout.
+                        const _: () = assert!(
+                            ::core::mem::size_of::<$Name>() == ::core::mem::size_of::<TransmutableIntoArchetype>()
+                                && ::core::mem::align_of::<$Name>() == ::core::mem::align_of::<TransmutableIntoArchetype>(),
+                            "the size and alignment of `TransmutableIntoArchetype` must equal those of the type"
+                        );
+                        const _: () = assert!(
+                            ::core::mem::size_of::<$Name>() == ::core::mem::size_of::<TransmutableFromArchetype>()
+                                && ::core::mem::align_of::<$Name>() == ::core::mem::align_of::<TransmutableFromArchetype>(),
+                            "the size and alignment of `TransmutableFromArchetype` must equal those of the type"
+                        );
+
+                        unsafe impl $crate::core::convert::transmute::TransmuteFrom<$Name, $crate::core::convert::transmute::options::NeglectStability> for TransmutableIntoArchetype {}
+                        unsafe impl $crate::core::convert::transmute::TransmuteFrom<TransmutableFromArchetype, $crate::core::convert::transmute::options::NeglectStability> for $Name {}
+
+                        impl $crate::core::convert::transmute::stability::PromiseTransmutableInto for TransmutableIntoArchetype { type Archetype = Self; }
+                        impl $crate::core::convert::transmute::stability::PromiseTransmutableFrom for TransmutableFromArchetype { type Archetype = Self; }
+
+                        impl $crate::core::convert::transmute::stability::PromiseTransmutableInto for $Name { type Archetype = TransmutableIntoArchetype; }
+                        impl $crate::core::convert::transmute::stability::PromiseTransmutableFrom for $Name { type Archetype = TransmutableFromArchetype; }
+                    };
+                    (@tuple_fields $layout:expr; [$i:tt $($is:tt)*]; $fty:ty $(, $rest:ty)*) => {
+                        $crate::promise_transmutable!(@tuple_fields
+                            $layout.field::<$fty>(stringify!($i), ::core::mem::offset_of!(Self, $i));
+                            [$($is)*];
+                            $($rest),*
+                        )
+                    };
+                    (@tuple_fields $layout:expr; []; $($rest:ty),+) => {
+                        ::core::compile_error!("`promise_transmutable!` supports tuple structs of at most 16 fields; use `#[derive(PromiseTransmutable)]` instead")
+                    };
+                    (@tuple_fields $layout:expr; [$($is:tt)*];) => {
+                        $layout
+                    };
+                }
+
+                #[doc(inline)]
+                pub use crate::promise_transmutable;
+
+                #[cfg(feature = "nightly-never-type")]
                 impl PromiseTransmutableInto for     ! {type Archetype = Self;}
+                #[cfg(feature = "nightly-never-type")]
                 impl PromiseTransmutableFrom for     ! {type Archetype = Self;}
 
                 impl PromiseTransmutableInto for    () {type Archetype = Self;}
@@ -390,17 +1593,79 @@ pub mod core {
                 impl PromiseTransmutableInto for usize {type Archetype = Self;}
                 impl PromiseTransmutableFrom for usize {type Archetype = Self;}
 
+                impl PromiseTransmutableInto for  bool {type Archetype = Self;}
+                impl PromiseTransmutableFrom for  bool {type Archetype = Self;}
+                impl PromiseTransmutableInto for  char {type Archetype = Self;}
+                impl PromiseTransmutableFrom for  char {type Archetype = Self;}
+
+                use core::num::{
+                    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+                    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+                };
+
+                impl PromiseTransmutableInto for    NonZeroI8 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for    NonZeroI8 {type Archetype = Self;}
+                impl PromiseTransmutableInto for   NonZeroI16 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   NonZeroI16 {type Archetype = Self;}
+                impl PromiseTransmutableInto for   NonZeroI32 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   NonZeroI32 {type Archetype = Self;}
+                impl PromiseTransmutableInto for   NonZeroI64 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   NonZeroI64 {type Archetype = Self;}
+                impl PromiseTransmutableInto for  NonZeroI128 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for  NonZeroI128 {type Archetype = Self;}
+                impl PromiseTransmutableInto for NonZeroIsize {type Archetype = Self;}
+                impl PromiseTransmutableFrom for NonZeroIsize {type Archetype = Self;}
+
+                impl PromiseTransmutableInto for    NonZeroU8 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for    NonZeroU8 {type Archetype = Self;}
+                impl PromiseTransmutableInto for   NonZeroU16 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   NonZeroU16 {type Archetype = Self;}
+                impl PromiseTransmutableInto for   NonZeroU32 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   NonZeroU32 {type Archetype = Self;}
+                impl PromiseTransmutableInto for   NonZeroU64 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   NonZeroU64 {type Archetype = Self;}
+                impl PromiseTransmutableInto for  NonZeroU128 {type Archetype = Self;}
+                impl PromiseTransmutableFrom for  NonZeroU128 {type Archetype = Self;}
+                impl PromiseTransmutableInto for NonZeroUsize {type Archetype = Self;}
+                impl PromiseTransmutableFrom for NonZeroUsize {type Archetype = Self;}
+
+                impl PromiseTransmutableInto for    Option<NonZeroI8> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for    Option<NonZeroI8> {type Archetype = Self;}
+                impl PromiseTransmutableInto for   Option<NonZeroI16> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   Option<NonZeroI16> {type Archetype = Self;}
+                impl PromiseTransmutableInto for   Option<NonZeroI32> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   Option<NonZeroI32> {type Archetype = Self;}
+                impl PromiseTransmutableInto for   Option<NonZeroI64> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   Option<NonZeroI64> {type Archetype = Self;}
+                impl PromiseTransmutableInto for  Option<NonZeroI128> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for  Option<NonZeroI128> {type Archetype = Self;}
+                impl PromiseTransmutableInto for Option<NonZeroIsize> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for Option<NonZeroIsize> {type Archetype = Self;}
+
+                impl PromiseTransmutableInto for    Option<NonZeroU8> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for    Option<NonZeroU8> {type Archetype = Self;}
+                impl PromiseTransmutableInto for   Option<NonZeroU16> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   Option<NonZeroU16> {type Archetype = Self;}
+                impl PromiseTransmutableInto for   Option<NonZeroU32> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   Option<NonZeroU32> {type Archetype = Self;}
+                impl PromiseTransmutableInto for   Option<NonZeroU64> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for   Option<NonZeroU64> {type Archetype = Self;}
+                impl PromiseTransmutableInto for  Option<NonZeroU128> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for  Option<NonZeroU128> {type Archetype = Self;}
+                impl PromiseTransmutableInto for Option<NonZeroUsize> {type Archetype = Self;}
+                impl PromiseTransmutableFrom for Option<NonZeroUsize> {type Archetype = Self;}
+
                 use core::marker::PhantomData;
                 impl<T: ?Sized> PromiseTransmutableInto for PhantomData<T> { type Archetype = Self; }
                 impl<T: ?Sized> PromiseTransmutableFrom for PhantomData<T> { type Archetype = Self; }
 
 
+                // An archetype is its own archetype. Requiring this of `T::Archetype`, rather than requiring that the forwarded archetype (e.g., `[T::Archetype; N]`) is itself promised, keeps the trait solver from recursing into these impls when proving them.
                 impl<T, const N: usize> PromiseTransmutableInto for [T; N]
                 where
                     T: PromiseTransmutableInto,
-                    [T::Archetype; N]
-                        : TransmuteFrom<Self, NeglectStability>
-                        + PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    [T::Archetype; N]: TransmuteFrom<Self, NeglectStability>,
                 {
                     type Archetype = [T::Archetype; N];
                 }
@@ -408,9 +1673,8 @@ pub mod core {
                 impl<T, const N: usize> PromiseTransmutableFrom for [T; N]
                 where
                     T: PromiseTransmutableFrom,
-                    [T::Archetype; N]
-                        : TransmuteInto<Self, NeglectStability>
-                        + PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    [T::Archetype; N]: TransmuteInto<Self, NeglectStability>,
                 {
                     type Archetype = [T::Archetype; N];
                 }
@@ -419,9 +1683,8 @@ pub mod core {
                 impl<T: ?Sized> PromiseTransmutableInto for *const T
                 where
                     T: PromiseTransmutableInto,
-                    *const T::Archetype
-                        : TransmuteFrom<Self, NeglectStability>
-                        + PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    *const T::Archetype: TransmuteFrom<Self, NeglectStability>,
                 {
                     type Archetype = *const T::Archetype;
                 }
@@ -429,9 +1692,8 @@ pub mod core {
                 impl<T: ?Sized> PromiseTransmutableFrom for *const T
                 where
                     T: PromiseTransmutableFrom,
-                    *const T::Archetype
-                        : TransmuteInto<Self, NeglectStability>
-                        + PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    *const T::Archetype: TransmuteInto<Self, NeglectStability>,
                 {
                     type Archetype = *const T::Archetype;
                 }
@@ -440,9 +1702,8 @@ pub mod core {
                 impl<T: ?Sized> PromiseTransmutableInto for *mut T
                 where
                     T: PromiseTransmutableInto,
-                    *mut T::Archetype
-                        : TransmuteFrom<Self, NeglectStability>
-                        + PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    *mut T::Archetype: TransmuteFrom<Self, NeglectStability>,
                 {
                     type Archetype = *mut T::Archetype;
                 }
@@ -450,9 +1711,8 @@ pub mod core {
                 impl<T: ?Sized> PromiseTransmutableFrom for *mut T
                 where
                     T: PromiseTransmutableFrom,
-                    *mut T::Archetype
-                        : TransmuteInto<Self, NeglectStability>
-                        + PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    *mut T::Archetype: TransmuteInto<Self, NeglectStability>,
                 {
                     type Archetype = *mut T::Archetype;
                 }
@@ -461,9 +1721,8 @@ pub mod core {
                 impl<'a, T: ?Sized> PromiseTransmutableInto for &'a T
                 where
                     T: PromiseTransmutableInto,
-                    &'a T::Archetype
-                        : TransmuteFrom<&'a T, NeglectStability>
-                        + PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    &'a T::Archetype: TransmuteFrom<&'a T, NeglectStability>,
                 {
                     type Archetype = &'a T::Archetype;
                 }
@@ -471,9 +1730,8 @@ pub mod core {
                 impl<'a, T: ?Sized> PromiseTransmutableFrom for &'a T
                 where
                     T: PromiseTransmutableFrom,
-                    &'a T::Archetype
-                        : TransmuteInto<&'a T, NeglectStability>
-                        + PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    &'a T::Archetype: TransmuteInto<&'a T, NeglectStability>,
                 {
                     type Archetype = &'a T::Archetype;
                 }
@@ -481,9 +1739,8 @@ pub mod core {
                 impl<'a, T: ?Sized> PromiseTransmutableInto for &'a mut T
                 where
                     T: PromiseTransmutableInto,
-                    &'a mut T::Archetype
-                        : TransmuteFrom<&'a mut T, NeglectStability>
-                        + PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    &'a mut T::Archetype: TransmuteFrom<&'a mut T, NeglectStability>,
                 {
                     type Archetype = &'a mut T::Archetype;
                 }
@@ -491,552 +1748,3890 @@ pub mod core {
                 impl<'a, T: ?Sized> PromiseTransmutableFrom for &'a mut T
                 where
                     T: PromiseTransmutableFrom,
-                    &'a mut T::Archetype
-                        : TransmuteInto<&'a mut T, NeglectStability>
-                        + PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    &'a mut T::Archetype: TransmuteInto<&'a mut T, NeglectStability>,
                 {
                     type Archetype = &'a mut T::Archetype;
                 }
-            }
 
-            /// Static checks that may be neglected when determining if two types are transmutable.
-            ///
-            /// The default value of the `Neglect` parameter of [TransmuteFrom] and [TransmuteInto], `()`, statically forbids transmutes that are unsafe, unsound, or unstable. However, you may explicitly opt-out of some static checks:
-            /// 
-            /// | Transmute Option    | Compromises | Usable With                                             |
-            /// |---------------------|-------------|---------------------------------------------------------|
-            /// | [NeglectStability]   | Stability   | `transmute_{from,into}`, `unsafe_transmute_{from,into}` |
-            /// | [NeglectAlignment]  | Safety      | `unsafe_transmute_{from,into}`                          |
-            /// | [NeglectValidity]   | Soundness   | `unsafe_transmute_{from,into}`                          |
-            /// 
-            /// The selection of multiple options is encoded by grouping them as a tuple; e.g., `(NeglectAlignment, NeglectValidity)` is a selection of both the [NeglectAlignment] and [NeglectValidity] options.
-            pub mod options {
-                use super::*;
+                use core::{
+                    cell::{Cell, UnsafeCell},
+                    mem::{ManuallyDrop, MaybeUninit},
+                    num::Wrapping,
+                };
 
-                /// Options that may be used with safe transmutations.
-                pub trait SafeTransmuteOptions: TransmuteOptions
-                {}
 
-                /// Options that may be used with unsafe transmutations.
-                pub trait TransmuteOptions: private::Sealed
-                {}
+                impl<T> PromiseTransmutableInto for Wrapping<T>
+                where
+                    T: PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    Wrapping<T::Archetype>: TransmuteFrom<Self, NeglectStability>,
+                {
+                    type Archetype = Wrapping<T::Archetype>;
+                }
 
-                impl SafeTransmuteOptions for () {}
-                impl TransmuteOptions for () {}
+                impl<T> PromiseTransmutableFrom for Wrapping<T>
+                where
+                    T: PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    Wrapping<T::Archetype>: TransmuteInto<Self, NeglectStability>,
+                {
+                    type Archetype = Wrapping<T::Archetype>;
+                }
 
-                /// Neglect the static stability check.
-                ///
-                /// By default, [TransmuteFrom] and [TransmuteInto] require that the [layouts of the source and destination types are SemVer-stable][super::stability]. The [NeglectStability] option disables this requirement.
-                ///
-                /// Prior to the adoption of the [stability declaration traits][super::stability], crate authors documented the layout guarantees of their types with doc comments. The [TransmuteFrom] and [TransmuteInto] traits and methods may be used with these types by requesting that the stability check is neglected; for instance:
-                /// 
-                /// ```rust
-                /// fn serialize<W: Write>(val : LibraryType, dst: W) -> std::io::Result<()>
-                /// where
-                ///     LibraryType: TransmuteInto<[u8; size_of::<LibraryType>()], NeglectStability>
-                /// {
-                ///     ...
-                /// }
-                /// ```
-                /// 
-                /// Neglecting stability over-eagerly cannot cause unsoundness or unsafety. For this reason, it is the only transmutation option available on the safe methods `transmute_from` and `transmute_into`. However, neglecting stability over-eagerly may cause your code to cease compiling if the authors of the source and destination types make changes that affect their layout.
-                /// 
-                /// By using the `NeglectStability` option to transmute types you do not own, you are committing to ensure that your reliance on these types' layouts is consistent with their documented stability guarantees.
-                pub struct NeglectStability;
 
-                // Uncomment this if/when constructibility is fully implemented:
-                impl SafeTransmuteOptions for NeglectStability {}
-                impl TransmuteOptions for NeglectStability {}
+                impl<T: ?Sized> PromiseTransmutableInto for Cell<T>
+                where
+                    T: PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    Cell<T::Archetype>: TransmuteFrom<Self, NeglectStability>,
+                {
+                    type Archetype = Cell<T::Archetype>;
+                }
 
-                /// Neglect the static alignment check.
-                ///
-                /// By default, [TransmuteFrom] and [TransmuteInto] are only implemented for references when the minimum alignment of the destination's referent type is no greater than the minimum alignment of the source's referent type. The `NeglectAlignment` option disables this requirement.
-                /// 
-                /// By using the `NeglectAlignment` option, you are committing to ensure that the transmuted reference satisfies the alignment requirements of the destination's referent type. For instance:
-                /// ```rust
-                /// /// Try to convert a `&T` into `&U`.
-                /// ///
-                /// /// This produces `None` if the referent isn't appropriately
-                /// /// aligned, as required by the destination type.
-                /// pub fn try_cast_ref<'t, 'u, T, U>(src: &'t T) -> Option<&'u U>
-                /// where
-                ///     &'t T: TransmuteInto<&'u U, NeglectAlignment>,
-                /// {
-                ///     if (src as *const T as usize) % align_of::<U>() != 0 {
-                ///         None
-                ///     } else {
-                ///         // Safe because we dynamically enforce the alignment
-                ///         // requirement, whose static check we chose to neglect.
-                ///         Some(unsafe { src.unsafe_transmute_into() })
-                ///     }
-                /// }
-                /// ```
-                pub struct NeglectAlignment;
-                impl TransmuteOptions for NeglectAlignment {}
+                impl<T: ?Sized> PromiseTransmutableFrom for Cell<T>
+                where
+                    T: PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    Cell<T::Archetype>: TransmuteInto<Self, NeglectStability>,
+                {
+                    type Archetype = Cell<T::Archetype>;
+                }
 
-                /// Partially neglect the static validity check.
-                /// 
-                /// By default, [TransmuteFrom] and [TransmuteInto]'s methods require that all instantiations of the source type are guaranteed to be valid instantiations of the destination type. This precludes transmutations which *might* be valid depending on the source value:
-                /// ```rust
-                /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
-                /// #[repr(u8)]
-                /// enum Bool {
-                ///     True = 1,
-                ///     False = 0,
-                /// }
-                /// 
-                /// /* ⚠️ This example intentionally does not compile. */
-                /// let _ : Bool  = some_u8_value.transmute_into(); // Compile Error!
-                /// ```
-                /// The [NeglectValidity] option disables this check.
-                /// 
-                /// By using the [NeglectValidity] option, you are committing to ensure dynamically source value is a valid instance of the destination type. For instance:
-                /// ```rust
-                /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
-                /// #[repr(u8)]
-                /// enum Bool {
-                ///     True = 1,
-                ///     False = 0,
-                /// }
-                /// 
-                /// pub trait TryIntoBool
-                /// {
-                ///     fn try_into_bool(self) -> Option<Bool>;
-                /// }
-                /// 
-                /// impl<T> TryIntoBool for T
-                /// where
-                ///     T: TransmuteInto<u8>,
-                ///     u8: TransmuteInto<Bool, NeglectValidity>
-                /// {
-                ///     fn try_into_bool(self) -> Option<Bool> {
-                ///         let val: u8 = self.transmute_into();
-                /// 
-                ///         if val > 1 {
-                ///             None
-                ///         } else {
-                ///             // Safe, because we've first verified that
-                ///             // `val` is a bit-valid instance of a boolean.
-                ///             Some(unsafe {val.unsafe_transmute_into()})
-                ///         }
-                ///     }
-                /// }
-                /// ```
-                /// 
-                /// Even with [NeglectValidity], the compiler will still statically reject transmutations that cannot possibly be valid:
-                /// ```compile_fail
-                /// #[derive(PromiseTransmutableInto)]
-                /// #[repr(C)] enum Foo { A = 24 }
-                /// 
-                /// #[derive(PromiseTransmutableFrom)]
-                /// #[repr(C)] enum Bar { Z = 42 }
-                /// 
-                /// let _ = <Bar as TransmuteFrom<Foo, NeglectValidity>::unsafe_transmute_from(Foo::N) // Compile error!
-                /// ```
-                pub struct NeglectValidity;
-                impl TransmuteOptions for NeglectValidity {}
-
-                /* FILL: Implementations for tuple combinations of options */
-
-                // prevent third-party implementations of `TransmuteOptions`
-                mod private {
-                    use super::*;
-
-                    pub trait Sealed {}
-
-                    impl Sealed for () {}
-                    impl Sealed for NeglectStability {}
-                    impl Sealed for NeglectAlignment {}
-                    impl Sealed for NeglectValidity {}
 
-                    /* FILL: Implementations for tuple combinations of options */
+                impl<T: ?Sized> PromiseTransmutableInto for UnsafeCell<T>
+                where
+                    T: PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    UnsafeCell<T::Archetype>: TransmuteFrom<Self, NeglectStability>,
+                {
+                    type Archetype = UnsafeCell<T::Archetype>;
                 }
-            }
-        }
-
 
-        /// **🌟** (Extension) Bit-altering conversions.
-        ///
-        /// This module demonstrates how the [transmute][module@transmute] API may be used to permit sound and complete slice casting and `Vec` casting.
-        ///
-        /// For more information on this extension, [**see here**](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#case-study-abstractions-for-fast-parsing).
-        #[cfg_attr(doc, unstable(feature = "cast", issue = "none"))]
-        pub mod cast {
+                impl<T: ?Sized> PromiseTransmutableFrom for UnsafeCell<T>
+                where
+                    T: PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    UnsafeCell<T::Archetype>: TransmuteInto<Self, NeglectStability>,
+                {
+                    type Archetype = UnsafeCell<T::Archetype>;
+                }
 
-            use options::*;
 
-            /// Cast `Self` into `Dst`.
-            ///
-            /// The reciprocal of [CastFrom]. This trait is implemented in terms of [CastFrom].
-            pub trait CastInto<Dst, Neglect=()>
-            where
-                Dst: CastFrom<Self, Neglect>,
-                Neglect: CastOptions,
-            {
-                /// Cast `self` into a value of type `Dst`, safely.
-                fn cast_into(self) -> Dst
+                impl<T: ?Sized> PromiseTransmutableInto for ManuallyDrop<T>
                 where
-                    Self: Sized,
-                    Dst: Sized,
-                    Neglect: SafeCastOptions,
+                    T: PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    ManuallyDrop<T::Archetype>: TransmuteFrom<Self, NeglectStability>,
                 {
-                    CastFrom::<_, Neglect>::cast_from(self)
+                    type Archetype = ManuallyDrop<T::Archetype>;
                 }
 
-                /// Cast `self` into a value of type `Dst`, potentially unsafely.
-                unsafe fn unsafe_cast_into(self) -> Dst
+                impl<T: ?Sized> PromiseTransmutableFrom for ManuallyDrop<T>
                 where
-                    Self: Sized,
-                    Dst: Sized,
-                    Neglect: CastOptions,
+                    T: PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    ManuallyDrop<T::Archetype>: TransmuteInto<Self, NeglectStability>,
                 {
-                    CastFrom::<_, Neglect>::unsafe_cast_from(self)
+                    type Archetype = ManuallyDrop<T::Archetype>;
                 }
-            }
 
-            impl<Src, Dst, Neglect> CastInto<Dst, Neglect> for Src
-            where
-                Dst: CastFrom<Self, Neglect>,
-                Neglect: CastOptions,
-            {}
 
-            /// Instantiate `Self` from a value of type `Src`.
-            ///
-            /// The reciprocal of [CastInto].
-            pub trait CastFrom<Src: ?Sized, Neglect=()>
-            where
-                Neglect: CastOptions,
-            {
-                /// Instantiate `Self` by casting a value of type `Src`, safely.
-                fn cast_from(src: Src) -> Self
+                impl<T> PromiseTransmutableInto for MaybeUninit<T>
                 where
-                    Src: Sized,
-                    Self: Sized,
-                    Neglect: SafeCastOptions
+                    T: PromiseTransmutableInto,
+                    T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+                    MaybeUninit<T::Archetype>: TransmuteFrom<Self, NeglectStability>,
                 {
-                    unsafe { CastFrom::<_,Neglect>::unsafe_cast_from(src) }
+                    type Archetype = MaybeUninit<T::Archetype>;
                 }
 
-                /// Instantiate `Self` by casting a value of type `Src`, potentially safely.
-                unsafe fn unsafe_cast_from(src: Src) -> Self
+                impl<T> PromiseTransmutableFrom for MaybeUninit<T>
                 where
-                    Src: Sized,
-                    Self: Sized,
-                    Neglect: CastOptions;
-            }
+                    T: PromiseTransmutableFrom,
+                    T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+                    MaybeUninit<T::Archetype>: TransmuteInto<Self, NeglectStability>,
+                {
+                    type Archetype = MaybeUninit<T::Archetype>;
+                }
 
-            /// Options for casting.
-            pub mod options {
 
-                /// The super-trait of all *safe* casting options.
-                #[marker] pub trait SafeCastOptions: CastOptions {}
+                // Types without fields report only their size and alignment.
+                macro_rules! opaque_layout_info {
+                    ($($T:ty),*) => {$(
+                        impl LayoutInfo for $T {
+                            fn layout_info() -> TypeLayout {
+                                TypeLayout::of::<Self>()
+                            }
+                        }
+                    )*}
+                }
 
-                /// The super-trait of all casting options.
-                #[marker] pub trait CastOptions {}
+                opaque_layout_info!((), f32, f64, bool, char);
+                #[cfg(feature = "nightly-never-type")]
+                opaque_layout_info!(!);
+                opaque_layout_info!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+                opaque_layout_info!(NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize);
+                opaque_layout_info!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+                opaque_layout_info!(Option<NonZeroI8>, Option<NonZeroI16>, Option<NonZeroI32>, Option<NonZeroI64>, Option<NonZeroI128>, Option<NonZeroIsize>);
+                opaque_layout_info!(Option<NonZeroU8>, Option<NonZeroU16>, Option<NonZeroU32>, Option<NonZeroU64>, Option<NonZeroU128>, Option<NonZeroUsize>);
 
-                impl SafeCastOptions for () {}
-                impl CastOptions for () {}
+                impl<T: ?Sized> LayoutInfo for PhantomData<T> {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>()
+                    }
+                }
 
-            }
-        }
+                impl<T: ?Sized> LayoutInfo for *const T {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>()
+                    }
+                }
 
-    }
+                impl<T: ?Sized> LayoutInfo for *mut T {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>()
+                    }
+                }
 
-    use convert::transmute::*;
+                impl<T: ?Sized> LayoutInfo for &T {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>()
+                    }
+                }
 
-    /// Basic functions for dealing with memory.
-    pub mod mem {
-        #[doc(no_inline)]
-        pub use core::mem::*;
+                impl<T: ?Sized> LayoutInfo for &mut T {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>()
+                    }
+                }
 
-        use crate::core::convert::transmute::{TransmuteFrom, stability::*, options::*};
+                impl<T: LayoutInfo, const N: usize> LayoutInfo for [T; N] {
+                    fn layout_info() -> TypeLayout {
+                        (0..N).fold(TypeLayout::of::<Self>(), |layout, i| {
+                            layout.field::<T>(format!("[{}]", i), i * core::mem::size_of::<T>())
+                        })
+                    }
+                }
 
-        /// Implemented if `align_of::<Self>() <= align_of::<Rhs>()`
-        #[cfg_attr(doc, unstable(feature = "query", issue = "none"))]
-        pub trait AlignLtEq<Rhs, Neglect=()>
-        where
-            Neglect: TransmuteOptions,
-        {}
+                impl<T: LayoutInfo> LayoutInfo for Wrapping<T> {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>().field::<T>("0", 0)
+                    }
+                }
 
-        /// By wrapping a type in a zero-sized array, we neutralize its validity and size qualities. The only quality by which `[Lhs; 0]` and `[Dst; 0]` can differ is their alignment. We check *only* if the alignment of `Lhs` is less than `Rhs` by transmuting between references of these zero-sized gadgets.
-        impl<Lhs, Rhs, Neglect> AlignLtEq<Rhs, Neglect> for Lhs
-        where
-            Neglect: TransmuteOptions,
-            for<'a> &'a [Lhs; 0]: TransmuteFrom<&'a [Rhs; 0], Neglect>
-        {}
+                // These wrappers are `#[repr(transparent)]` (or, for `MaybeUninit`, a `#[repr(transparent)]` union); their private field is reported as `value`.
+                impl<T: LayoutInfo> LayoutInfo for Cell<T> {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>().field::<T>("value", 0)
+                    }
+                }
 
-        /// Implemented if `align_of::<Self>() == align_of::<Rhs>()`
-        ///
-        /// See the [`Vec` casting demonstration][super::convert::cast::CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] for an example of its use.
-        #[cfg_attr(doc, unstable(feature = "query", issue = "none"))]
-        pub trait AlignEq<Rhs, Neglect=()>
-        where
-            Neglect: TransmuteOptions,
-        {}
+                impl<T: LayoutInfo> LayoutInfo for UnsafeCell<T> {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>().field::<T>("value", 0)
+                    }
+                }
 
-        /// See [AlignLtEq].
-        impl<Lhs, Rhs, Neglect> AlignEq<Rhs, Neglect> for Lhs
-        where
-            Neglect: TransmuteOptions,
-            Lhs: AlignLtEq<Rhs>,
-            Rhs: AlignLtEq<Lhs>,
-        {}
+                impl<T: LayoutInfo> LayoutInfo for ManuallyDrop<T> {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>().field::<T>("value", 0)
+                    }
+                }
 
-        mod private {
-            use core::mem::MaybeUninit;
+                impl<T: LayoutInfo> LayoutInfo for MaybeUninit<T> {
+                    fn layout_info() -> TypeLayout {
+                        TypeLayout::of::<Self>().field::<T>("value", 0)
+                    }
+                }
 
-            // The alignment of this struct is always equal to `max(align_of::<A>(), align_of::<T>())`.
-            // Its validity is always equal to `MaybeUninit<T>`.
-            // Its size equals `T`
-            /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
-            #[repr(C)]
-            pub struct Aligned<A, T>(pub [A; 0], pub MaybeUninit<T>);
-        }
+                // Tuples have no guaranteed layout, so the offsets of their fields are measured.
+                macro_rules! tuple_layout_info {
+                    ($($i:tt $T:ident)+) => {
+                        impl<$($T: LayoutInfo,)+> LayoutInfo for ($($T,)+) {
+                            fn layout_info() -> TypeLayout {
+                                let tuple = MaybeUninit::<Self>::uninit();
+                                let base = tuple.as_ptr();
+                                TypeLayout::of::<Self>()
+                                    $(.field::<$T>(stringify!($i), unsafe { core::ptr::addr_of!((*base).$i) as usize - base as usize }))+
+                            }
+                        }
+                    }
+                }
 
-        /// Implemented if `size_of::<Self>() <= size_of::<Rhs>()`
-        #[cfg_attr(doc, unstable(feature = "query", issue = "none"))]
-        pub trait SizeLtEq<Rhs, Neglect=()>
-        where
-            Neglect: TransmuteOptions,
-        {}
+                tuple_layout_info! { 0 A }
+                tuple_layout_info! { 0 A 1 B }
+                tuple_layout_info! { 0 A 1 B 2 C }
+                tuple_layout_info! { 0 A 1 B 2 C 3 D }
+                tuple_layout_info! { 0 A 1 B 2 C 3 D 4 E }
+                tuple_layout_info! { 0 A 1 B 2 C 3 D 4 E 5 F }
+                tuple_layout_info! { 0 A 1 B 2 C 3 D 4 E 5 F 6 G }
+                tuple_layout_info! { 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H }
+                tuple_layout_info! { 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I }
+                tuple_layout_info! { 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J }
+                tuple_layout_info! { 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J 10 K }
+                tuple_layout_info! { 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J 10 K 11 L }
+            }
 
-        /// We wrap the types in a struct that neutralizes their alignment and validity differences, leaving size as the only quality that might differ between `Aligned<Rhs, Lhs>` and `Aligned<Lhs, Rhs>`.
-        #[cfg_attr(doc, unstable(feature = "query", issue = "none"))]
-        impl<Lhs, Rhs, Neglect> SizeLtEq<Rhs, Neglect> for Lhs
-        where
-            Neglect: TransmuteOptions,
-            for<'a> &'a private::Aligned<Rhs, Lhs>: TransmuteFrom<&'a private::Aligned<Lhs, Rhs>>,
-        {}
+            /// Integers with an explicit byte order.
+            ///
+            /// The native integer types are laid out in the byte order of the target, so transmuting them from bytes received over a network (or read from a file) produces values whose interpretation depends on the host. The integer types of this module are instead laid out in the byte order of their `O` parameter, and have a minimum alignment of `1`, so they may be transmuted freely from, and referenced within, byte arrays:
+            /// ```rust,ignore
+            /// use core::convert::transmute::byteorder::{U16, NetworkEndian};
+            ///
+            /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+            /// #[repr(C)]
+            /// pub struct UdpHeader {
+            ///     pub src_port: U16<NetworkEndian>,
+            ///     pub dst_port: U16<NetworkEndian>,
+            ///     pub length:   U16<NetworkEndian>,
+            ///     pub checksum: U16<NetworkEndian>,
+            /// }
+            ///
+            /// let packet: &[u8; 8] = &[0x30, 0x39, 0x00, 0x35, 0x00, 0x08, 0x00, 0x00];
+            /// let header: &UdpHeader = packet.transmute_into();
+            ///
+            /// assert_eq!(header.src_port.get(), 12345);
+            /// assert_eq!(header.dst_port.get(), 53);
+            /// ```
+            #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "byteorder", issue = "none"))]
+            pub mod byteorder {
+                use core::{fmt, hash::Hash, marker::PhantomData};
+                use super::stability::{PromiseTransmutableFrom, PromiseTransmutableInto, LayoutInfo, TypeLayout};
 
-        /// Implemented if `size_of::<Self>() == size_of::<Rhs>()`
-        ///
-        /// See the [`Vec` casting demonstration][super::convert::cast::CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] for an example of its use.
-        #[cfg_attr(doc, unstable(feature = "query", issue = "none"))]
-        pub trait SizeEq<Rhs, Neglect=()>
-        where
-            Neglect: TransmuteOptions,
-        {}
+                /// A byte order: either [BigEndian] or [LittleEndian].
+                pub trait ByteOrder
+                    : Copy + Eq + Hash + fmt::Debug
+                    + private::Sealed
+                {
+                    /// Whether this byte order is big-endian.
+                    const IS_BIG_ENDIAN: bool;
+                }
 
-        /// See [SizeLtEq].
-        #[cfg_attr(doc, unstable(feature = "query", issue = "none"))]
-        impl<Lhs, Rhs, Neglect> SizeEq<Rhs, Neglect> for Lhs
-        where
-            Neglect: TransmuteOptions,
-            Lhs: SizeLtEq<Rhs>,
-            Rhs: SizeLtEq<Lhs>,
-        {}
-    }
+                /// Big-endian byte order; the most significant byte comes first.
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+                pub enum BigEndian {}
 
-    /// A dynamically-sized view into a contiguous sequence, `[T]`.
-    pub mod slice {
-        #[doc(no_inline)]
-        pub use core::slice::*;
+                /// Little-endian byte order; the least significant byte comes first.
+                #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+                pub enum LittleEndian {}
 
-        use crate::core::convert::{
-            transmute::{
-                TransmuteFrom,
-                options::{SafeTransmuteOptions, TransmuteOptions},
-            },
-            cast::{
-                CastFrom,
-                options::{
-                    SafeCastOptions,
-                    CastOptions,
-                },
-            },
-        };
+                /// The byte order of network protocols; i.e., [BigEndian].
+                pub type NetworkEndian = BigEndian;
 
-        use core::{
-            mem::{size_of, size_of_val},
-            slice
-        };
+                /// The byte order of the target.
+                #[cfg(target_endian = "big")]
+                pub type NativeEndian = BigEndian;
 
-        /// **🌟** *Safe* options for casting **slices**.
-        ///
-        /// Slice casting transmutes the contents of the slice, and adjusts the slice's length as needed. All [SafeTransmuteOptions] are [SafeSliceCastOptions].
-        #[cfg_attr(doc, unstable(feature = "cast", issue = "none"))]
-        pub trait SafeSliceCastOptions
-            : SafeCastOptions
-            + SafeTransmuteOptions
-            + SliceCastOptions
-        {}
+                /// The byte order of the target.
+                #[cfg(target_endian = "little")]
+                pub type NativeEndian = LittleEndian;
 
-        /// **🌟** Options for casting **slices**.
-        ///
-        /// Slice casting transmutes the contents of the slice, and adjusts the slice's length as needed. All [TransmuteOptions] are [SliceCastOptions].
-        #[cfg_attr(doc, unstable(feature = "cast", issue = "none"))]
-        pub trait SliceCastOptions
-            : CastOptions
-            + TransmuteOptions
-        {}
+                impl ByteOrder for BigEndian { const IS_BIG_ENDIAN: bool = true; }
+                impl ByteOrder for LittleEndian { const IS_BIG_ENDIAN: bool = false; }
 
-        impl<Neglect: SafeSliceCastOptions> SafeCastOptions for Neglect {}
-        impl<Neglect: SafeTransmuteOptions> SafeSliceCastOptions for Neglect {}
+                mod private {
+                    pub trait Sealed {}
 
-        impl<Neglect: SliceCastOptions> CastOptions for Neglect {}
-        impl<Neglect: TransmuteOptions> SliceCastOptions for Neglect {}
+                    impl Sealed for super::BigEndian {}
+                    impl Sealed for super::LittleEndian {}
 
-        /// #### `&[Src]` **🠮** `&[Dst]`
-        /// If the sizes of `Src` and `Dst` differ, the length of the output slice is adjusted as-needed.
-        ///
-        /// ##### Static Options
-        /// See [SliceCastOptions] and [SafeSliceCastOptions].
-        ///
-        /// ##### Example
-        /// ```rust
-        /// let src : &[i8] = &[-1, -2, -3, -4][..];
-        /// let dst : &[[u8; 2]] = src.cast_into();
-        /// assert_eq!(dst, &[[-255, -254], [-253, -252]][..]);
-        /// ```
-        impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i [Src], Neglect> for &'o [Dst]
-        where
-            Neglect: SliceCastOptions,
-            &'o [Dst; 1]: TransmuteFrom<&'i [Src; usize::MAX], Neglect>
-        {
-            #[doc(hidden)]
-            #[inline(always)]
-            unsafe fn unsafe_cast_from(src: &'i [Src]) -> &'o [Dst]
-            {
-                let len = size_of_val(src).checked_div(size_of::<Dst>()).unwrap_or(0);
-                unsafe { slice::from_raw_parts(src.as_ptr() as *const Dst, len) }
-            }
-        }
+                    pub trait SealedInteger {}
+                }
 
-        /// #### `&mut [Src]` **🠮** `&mut [Dst]`
-        /// If the sizes of `Src` and `Dst` differ, the length of the output slice is adjusted as-needed.
-        ///
-        /// ##### Static Options
-        /// See [SliceCastOptions] and [SafeSliceCastOptions].
-        ///
-        /// ##### Example
-        /// ```rust
+                macro_rules! byteorder_int {
+                    ($(#[$attr:meta])* $Name:ident, $int:ident, $size:literal) => {
+                        $(#[$attr])*
+                        ///
+                        /// Its minimum alignment is `1`, so it may be transmuted from, and referenced within, byte arrays.
+                        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+                        #[repr(transparent)]
+                        pub struct $Name<O: ByteOrder>([u8; $size], PhantomData<O>);
+
+                        impl<O: ByteOrder> $Name<O> {
+                            /// Produce the representation of `n` in byte order `O`.
+                            #[inline(always)]
+                            pub fn new(n: $int) -> Self {
+                                let bytes = if O::IS_BIG_ENDIAN { n.to_be_bytes() } else { n.to_le_bytes() };
+                                $Name(bytes, PhantomData)
+                            }
+
+                            /// Produce the native value of `self`.
+                            #[inline(always)]
+                            pub fn get(self) -> $int {
+                                if O::IS_BIG_ENDIAN { $int::from_be_bytes(self.0) } else { $int::from_le_bytes(self.0) }
+                            }
+
+                            /// Set the value of `self` to `n`.
+                            #[inline(always)]
+                            pub fn set(&mut self, n: $int) {
+                                *self = Self::new(n);
+                            }
+                        }
+
+                        impl<O: ByteOrder> From<$int> for $Name<O> {
+                            #[inline(always)]
+                            fn from(n: $int) -> Self {
+                                Self::new(n)
+                            }
+                        }
+
+                        impl<O: ByteOrder> From<$Name<O>> for $int {
+                            #[inline(always)]
+                            fn from(n: $Name<O>) -> Self {
+                                n.get()
+                            }
+                        }
+
+                        impl<O: ByteOrder> Default for $Name<O> {
+                            fn default() -> Self {
+                                Self::new(0)
+                            }
+                        }
+
+                        impl<O: ByteOrder> fmt::Debug for $Name<O> {
+                            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                                f.debug_tuple(stringify!($Name)).field(&self.get()).finish()
+                            }
+                        }
+
+                        impl<O: ByteOrder> fmt::Display for $Name<O> {
+                            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                                fmt::Display::fmt(&self.get(), f)
+                            }
+                        }
+
+                        impl<O: ByteOrder> PromiseTransmutableInto for $Name<O> {type Archetype = Self;}
+                        impl<O: ByteOrder> PromiseTransmutableFrom for $Name<O> {type Archetype = Self;}
+
+                        impl<O: ByteOrder> LayoutInfo for $Name<O> {
+                            fn layout_info() -> TypeLayout {
+                                TypeLayout::of::<Self>()
+                            }
+                        }
+                    }
+                }
+
+                byteorder_int! {
+                    /// A `u16` in byte order `O`.
+                    U16, u16, 2
+                }
+                byteorder_int! {
+                    /// A `u32` in byte order `O`.
+                    U32, u32, 4
+                }
+                byteorder_int! {
+                    /// A `u64` in byte order `O`.
+                    U64, u64, 8
+                }
+                byteorder_int! {
+                    /// A `u128` in byte order `O`.
+                    U128, u128, 16
+                }
+                byteorder_int! {
+                    /// An `i16` in byte order `O`.
+                    I16, i16, 2
+                }
+                byteorder_int! {
+                    /// An `i32` in byte order `O`.
+                    I32, i32, 4
+                }
+                byteorder_int! {
+                    /// An `i64` in byte order `O`.
+                    I64, i64, 8
+                }
+                byteorder_int! {
+                    /// An `i128` in byte order `O`.
+                    I128, i128, 16
+                }
+
+                /// **🌟** A primitive integer with a counterpart, in this module, for each [ByteOrder].
+                ///
+                /// Used by the [SwapBytes][crate::core::convert::cast::options::SwapBytes] cast option.
+                pub trait Integer: Copy + private::SealedInteger {
+                    /// This integer, in byte order `O`; e.g., [`U32<O>`][U32] for `u32`.
+                    type InOrder<O: ByteOrder>: Copy + Into<Self>;
+                }
+
+                macro_rules! integer {
+                    ($($int:ident => $Name:ident),*) => {$(
+                        impl Integer for $int { type InOrder<O: ByteOrder> = $Name<O>; }
+                        impl private::SealedInteger for $int {}
+                    )*};
+                }
+
+                integer!(u16 => U16, u32 => U32, u64 => U64, u128 => U128, i16 => I16, i32 => I32, i64 => I64, i128 => I128);
+            }
+
+            /// Static checks that may be neglected when determining if two types are transmutable.
+            ///
+            /// The default value of the `Neglect` parameter of [TransmuteFrom] and [TransmuteInto], `()`, statically forbids transmutes that are unsafe, unsound, or unstable. However, you may explicitly opt-out of some static checks:
+            /// 
+            /// | Transmute Option    | Compromises | Usable With                                             |
+            /// |---------------------|-------------|---------------------------------------------------------|
+            /// | [NeglectStability]   | Stability   | `transmute_{from,into}`, `unsafe_transmute_{from,into}` |
+            /// | [NeglectAlignment]  | Safety      | `unsafe_transmute_{from,into}`                          |
+            /// | [NeglectValidity]   | Soundness   | `unsafe_transmute_{from,into}`                          |
+            /// 
+            /// The selection of multiple options is encoded by grouping them as a tuple; e.g., `(NeglectAlignment, NeglectValidity)` is a selection of both the [NeglectAlignment] and [NeglectValidity] options. The order of options within a tuple is insignificant.
+            ///
+            /// A combination of options is only usable with the safe methods `transmute_{from,into}` if *every* option in it is. Since [NeglectStability] is the only safe option, every tuple of options is restricted to `unsafe_transmute_{from,into}`:
+            /// ```rust
+            /// use rfc::core::convert::transmute::{TransmuteFrom, options::*};
+            ///
+            /// // `NeglectStability` alone is safe:
+            /// let _ : u32 = <u32 as TransmuteFrom<u32, NeglectStability>>::transmute_from(0u32);
+            ///
+            /// // Combinations of options are accepted in any order by the unsafe methods:
+            /// unsafe fn neglect_alignment_and_validity<Src, Dst>(src: Src) -> Dst
+            /// where
+            ///     Dst: TransmuteFrom<Src, (NeglectAlignment, NeglectValidity)>
+            ///        + TransmuteFrom<Src, (NeglectValidity, NeglectAlignment)>
+            ///        + TransmuteFrom<Src, (NeglectStability, NeglectValidity, NeglectAlignment)>,
+            /// {
+            ///     unsafe { <Dst as TransmuteFrom<Src, (NeglectValidity, NeglectAlignment)>>::unsafe_transmute_from(src) }
+            /// }
+            /// ```
+            /// But combinations are rejected by the safe methods, even if they include [NeglectStability]:
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::{TransmuteFrom, options::*};
+            ///
+            /// fn transmute<Src, Dst>(src: Src) -> Dst
+            /// where
+            ///     Dst: TransmuteFrom<Src, (NeglectStability, NeglectAlignment)>,
+            /// {
+            ///     Dst::transmute_from(src)
+            ///     // error[E0277]: the trait bound `(NeglectStability, NeglectAlignment): SafeTransmuteOptions` is not satisfied
+            /// }
+            /// ```
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::{TransmuteInto, options::*};
+            ///
+            /// fn transmute<Src, Dst>(src: Src) -> Dst
+            /// where
+            ///     Src: TransmuteInto<Dst, (NeglectValidity, NeglectStability)>,
+            /// {
+            ///     src.transmute_into()
+            ///     // error[E0277]: the trait bound `(NeglectValidity, NeglectStability): SafeTransmuteOptions` is not satisfied
+            /// }
+            /// ```
+            /// Options may not be repeated:
+            /// ```compile_fail,E0277
+            /// use rfc::core::convert::transmute::{TransmuteFrom, options::*};
+            ///
+            /// unsafe fn transmute<Src, Dst>(src: Src) -> Dst
+            /// where
+            ///     Dst: TransmuteFrom<Src, (NeglectAlignment, NeglectAlignment)>,
+            ///     // error[E0277]: the trait bound `(NeglectAlignment, NeglectAlignment): TransmuteOptions` is not satisfied
+            /// {
+            ///     unsafe { Dst::unsafe_transmute_from(src) }
+            /// }
+            /// ```
+            pub mod options {
+                #[allow(unused_imports)] // for intra-doc links
+                use super::*;
+
+                /// Options that may be used with safe transmutations.
+                pub trait SafeTransmuteOptions: TransmuteOptions
+                {}
+
+                /// Options that may be used with unsafe transmutations.
+                pub trait TransmuteOptions: private::Sealed
+                {}
+
+                impl SafeTransmuteOptions for () {}
+                impl TransmuteOptions for () {}
+
+                /// Neglect the static stability check.
+                ///
+                /// By default, [TransmuteFrom] and [TransmuteInto] require that the [layouts of the source and destination types are SemVer-stable][super::stability]. The [NeglectStability] option disables this requirement.
+                ///
+                /// Prior to the adoption of the [stability declaration traits][super::stability], crate authors documented the layout guarantees of their types with doc comments. The [TransmuteFrom] and [TransmuteInto] traits and methods may be used with these types by requesting that the stability check is neglected; for instance:
+                /// 
+                /// ```rust,ignore
+                /// fn serialize<W: Write>(val : LibraryType, dst: W) -> std::io::Result<()>
+                /// where
+                ///     LibraryType: TransmuteInto<[u8; size_of::<LibraryType>()], NeglectStability>
+                /// {
+                ///     ...
+                /// }
+                /// ```
+                /// 
+                /// Neglecting stability over-eagerly cannot cause unsoundness or unsafety. For this reason, it is the only transmutation option available on the safe methods `transmute_from` and `transmute_into`. However, neglecting stability over-eagerly may cause your code to cease compiling if the authors of the source and destination types make changes that affect their layout.
+                /// 
+                /// By using the `NeglectStability` option to transmute types you do not own, you are committing to ensure that your reliance on these types' layouts is consistent with their documented stability guarantees.
+                pub struct NeglectStability;
+
+                // Uncomment this if/when constructibility is fully implemented:
+                impl SafeTransmuteOptions for NeglectStability {}
+                impl TransmuteOptions for NeglectStability {}
+
+                /// Neglect the static alignment check.
+                ///
+                /// By default, [TransmuteFrom] and [TransmuteInto] are only implemented for references when the minimum alignment of the destination's referent type is no greater than the minimum alignment of the source's referent type. The `NeglectAlignment` option disables this requirement.
+                /// 
+                /// By using the `NeglectAlignment` option, you are committing to ensure that the transmuted reference satisfies the alignment requirements of the destination's referent type. For instance:
+                /// ```rust
+                /// # use core::mem::align_of;
+                /// # use rfc::core::convert::transmute::{TransmuteInto, options::NeglectAlignment};
+                /// /// Try to convert a `&T` into `&U`.
+                /// ///
+                /// /// This produces `None` if the referent isn't appropriately
+                /// /// aligned, as required by the destination type.
+                /// pub fn try_cast_ref<'t, 'u, T, U>(src: &'t T) -> Option<&'u U>
+                /// where
+                ///     &'t T: TransmuteInto<&'u U, NeglectAlignment>,
+                /// {
+                ///     if (src as *const T as usize) % align_of::<U>() != 0 {
+                ///         None
+                ///     } else {
+                ///         // Safe because we dynamically enforce the alignment
+                ///         // requirement, whose static check we chose to neglect.
+                ///         Some(unsafe { src.unsafe_transmute_into() })
+                ///     }
+                /// }
+                /// ```
+                pub struct NeglectAlignment;
+                impl TransmuteOptions for NeglectAlignment {}
+
+                /// Partially neglect the static validity check.
+                /// 
+                /// By default, [TransmuteFrom] and [TransmuteInto]'s methods require that all instantiations of the source type are guaranteed to be valid instantiations of the destination type. This precludes transmutations which *might* be valid depending on the source value:
+                /// ```compile_fail,E0277
+                /// # use rfc::core::convert::transmute::{TransmuteInto, stability::*};
+                /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+                /// #[repr(u8)]
+                /// enum Bool {
+                ///     True = 1,
+                ///     False = 0,
+                /// }
+                /// 
+                /// # let some_u8_value = 1u8;
+                /// /* ⚠️ This example intentionally does not compile. */
+                /// let _ : Bool  = some_u8_value.transmute_into(); // Compile Error!
+                /// ```
+                /// The [NeglectValidity] option disables this check.
+                /// 
+                /// By using the [NeglectValidity] option, you are committing to ensure dynamically source value is a valid instance of the destination type. For instance:
+                /// ```rust,ignore
+                /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+                /// #[repr(u8)]
+                /// enum Bool {
+                ///     True = 1,
+                ///     False = 0,
+                /// }
+                /// 
+                /// pub trait TryIntoBool
+                /// {
+                ///     fn try_into_bool(self) -> Option<Bool>;
+                /// }
+                /// 
+                /// impl<T> TryIntoBool for T
+                /// where
+                ///     T: TransmuteInto<u8>,
+                ///     u8: TransmuteInto<Bool, NeglectValidity>
+                /// {
+                ///     fn try_into_bool(self) -> Option<Bool> {
+                ///         let val: u8 = self.transmute_into();
+                /// 
+                ///         if val > 1 {
+                ///             None
+                ///         } else {
+                ///             // Safe, because we've first verified that
+                ///             // `val` is a bit-valid instance of a boolean.
+                ///             Some(unsafe {val.unsafe_transmute_into()})
+                ///         }
+                ///     }
+                /// }
+                /// ```
+                /// [TryTransmuteFrom] performs this check for you, with no unsafe code required.
+                /// 
+                /// Even with [NeglectValidity], the compiler will still statically reject transmutations that cannot possibly be valid:
+                /// ```compile_fail,E0277
+                /// # use rfc::core::convert::transmute::{TransmuteFrom, options::NeglectValidity, stability::*};
+                /// #[derive(PromiseTransmutableInto)]
+                /// #[repr(C)] enum Foo { A = 24 }
+                /// 
+                /// #[derive(PromiseTransmutableFrom)]
+                /// #[repr(C)] enum Bar { Z = 42 }
+                /// 
+                /// let _ = unsafe { <Bar as TransmuteFrom<Foo, NeglectValidity>>::unsafe_transmute_from(Foo::A) }; // Compile error!
+                /// ```
+                pub struct NeglectValidity;
+                impl TransmuteOptions for NeglectValidity {}
+
+                // Every combination of options, in any order. Each of these combinations includes at least one unsafe option, so none of them implement `SafeTransmuteOptions`.
+                impl TransmuteOptions for (NeglectStability, NeglectAlignment) {}
+                impl TransmuteOptions for (NeglectAlignment, NeglectStability) {}
+                impl TransmuteOptions for (NeglectStability, NeglectValidity) {}
+                impl TransmuteOptions for (NeglectValidity, NeglectStability) {}
+                impl TransmuteOptions for (NeglectAlignment, NeglectValidity) {}
+                impl TransmuteOptions for (NeglectValidity, NeglectAlignment) {}
+
+                impl TransmuteOptions for (NeglectStability, NeglectAlignment, NeglectValidity) {}
+                impl TransmuteOptions for (NeglectStability, NeglectValidity, NeglectAlignment) {}
+                impl TransmuteOptions for (NeglectAlignment, NeglectStability, NeglectValidity) {}
+                impl TransmuteOptions for (NeglectAlignment, NeglectValidity, NeglectStability) {}
+                impl TransmuteOptions for (NeglectValidity, NeglectStability, NeglectAlignment) {}
+                impl TransmuteOptions for (NeglectValidity, NeglectAlignment, NeglectStability) {}
+
+                // prevent third-party implementations of `TransmuteOptions`
+                pub(crate) mod private {
+                    use super::*;
+                    use crate::layout::Options;
+
+                    pub trait Sealed {
+                        // The options, as modeled by the `layout` module.
+                        const MODEL: Options;
+                    }
+
+                    macro_rules! sealed {
+                        ($($Neglect:ty => ($stability:expr, $alignment:expr, $validity:expr);)*) => {$(
+                            impl Sealed for $Neglect {
+                                const MODEL: Options = Options {
+                                    neglect_stability: $stability,
+                                    neglect_alignment: $alignment,
+                                    neglect_validity: $validity,
+                                };
+                            }
+                        )*};
+                    }
+
+                    // Each selection of options, as `(neglect_stability, neglect_alignment, neglect_validity)`.
+                    sealed! {
+                        () => (false, false, false);
+                        NeglectStability => (true, false, false);
+                        NeglectAlignment => (false, true, false);
+                        NeglectValidity => (false, false, true);
+
+                        (NeglectStability, NeglectAlignment) => (true, true, false);
+                        (NeglectAlignment, NeglectStability) => (true, true, false);
+                        (NeglectStability, NeglectValidity) => (true, false, true);
+                        (NeglectValidity, NeglectStability) => (true, false, true);
+                        (NeglectAlignment, NeglectValidity) => (false, true, true);
+                        (NeglectValidity, NeglectAlignment) => (false, true, true);
+
+                        (NeglectStability, NeglectAlignment, NeglectValidity) => (true, true, true);
+                        (NeglectStability, NeglectValidity, NeglectAlignment) => (true, true, true);
+                        (NeglectAlignment, NeglectStability, NeglectValidity) => (true, true, true);
+                        (NeglectAlignment, NeglectValidity, NeglectStability) => (true, true, true);
+                        (NeglectValidity, NeglectStability, NeglectAlignment) => (true, true, true);
+                        (NeglectValidity, NeglectAlignment, NeglectStability) => (true, true, true);
+                    }
+                }
+            }
+        }
+
+
+        /// **🌟** (Extension) Bit-altering conversions.
+        ///
+        /// This module demonstrates how the [transmute][module@transmute] API may be used to permit sound and complete slice casting and `Vec` casting.
+        ///
+        /// For more information on this extension, [**see here**](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#case-study-abstractions-for-fast-parsing).
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        pub mod cast {
+
+            use options::*;
+
+            /// Cast `Self` into `Dst`.
+            ///
+            /// The reciprocal of [CastFrom]. This trait is implemented in terms of [CastFrom].
+            pub trait CastInto<Dst, Neglect=()>
+            where
+                Dst: CastFrom<Self, Neglect>,
+                Neglect: CastOptions,
+            {
+                /// Cast `self` into a value of type `Dst`, safely.
+                fn cast_into(self) -> Dst
+                where
+                    Self: Sized,
+                    Dst: Sized,
+                    Neglect: SafeCastOptions,
+                {
+                    CastFrom::<_, Neglect>::cast_from(self)
+                }
+
+                /// Cast `self` into a value of type `Dst`, potentially unsafely.
+                unsafe fn unsafe_cast_into(self) -> Dst
+                where
+                    Self: Sized,
+                    Dst: Sized,
+                    Neglect: CastOptions,
+                {
+                    CastFrom::<_, Neglect>::unsafe_cast_from(self)
+                }
+            }
+
+            impl<Src, Dst, Neglect> CastInto<Dst, Neglect> for Src
+            where
+                Dst: CastFrom<Self, Neglect>,
+                Neglect: CastOptions,
+            {}
+
+            /// Instantiate `Self` from a value of type `Src`.
+            ///
+            /// The reciprocal of [CastInto].
+            ///
+            /// **🌟** Like [TransmuteFrom][super::transmute::TransmuteFrom], this trait's methods cannot be called in a `const` context. The slice casts are also provided as `const` functions; see [cast_slice][crate::core::slice::cast_slice].
+            pub trait CastFrom<Src: ?Sized, Neglect=()>
+            where
+                Neglect: CastOptions,
+            {
+                /// Instantiate `Self` by casting a value of type `Src`, safely.
+                fn cast_from(src: Src) -> Self
+                where
+                    Src: Sized,
+                    Self: Sized,
+                    Neglect: SafeCastOptions
+                {
+                    unsafe { CastFrom::<_,Neglect>::unsafe_cast_from(src) }
+                }
+
+                /// Instantiate `Self` by casting a value of type `Src`, potentially safely.
+                unsafe fn unsafe_cast_from(src: Src) -> Self
+                where
+                    Src: Sized,
+                    Self: Sized,
+                    Neglect: CastOptions;
+            }
+
+            /// Fallibly cast `Self` into `Dst`.
+            ///
+            /// The reciprocal of [TryCastFrom]. This trait is implemented in terms of [TryCastFrom].
+            pub trait TryCastInto<Dst, Neglect=()>
+            where
+                Dst: TryCastFrom<Self, Neglect>,
+                Neglect: CastOptions,
+            {
+                /// Cast `self` into a value of type `Dst`, safely, if it is a valid source for the cast.
+                fn try_cast_into(self) -> Result<Dst, CastError<Self>>
+                where
+                    Self: Sized,
+                    Dst: Sized,
+                    Neglect: SafeCastOptions,
+                {
+                    TryCastFrom::<_, Neglect>::try_cast_from(self)
+                }
+
+                /// Cast `self` into a value of type `Dst`, potentially unsafely, if it is a valid source for the cast.
+                unsafe fn unsafe_try_cast_into(self) -> Result<Dst, CastError<Self>>
+                where
+                    Self: Sized,
+                    Dst: Sized,
+                    Neglect: CastOptions,
+                {
+                    TryCastFrom::<_, Neglect>::unsafe_try_cast_from(self)
+                }
+            }
+
+            impl<Src, Dst, Neglect> TryCastInto<Dst, Neglect> for Src
+            where
+                Dst: TryCastFrom<Self, Neglect>,
+                Neglect: CastOptions,
+            {}
+
+            /// Fallibly instantiate `Self` from a value of type `Src`.
+            ///
+            /// The reciprocal of [TryCastInto]. Unlike [CastFrom], the requirements of the cast are checked dynamically; if they are not met, the source value is returned in a [CastError].
+            pub trait TryCastFrom<Src: ?Sized, Neglect=()>
+            where
+                Neglect: CastOptions,
+            {
+                /// Instantiate `Self` by casting a value of type `Src`, safely, if it is a valid source for the cast.
+                fn try_cast_from(src: Src) -> Result<Self, CastError<Src>>
+                where
+                    Src: Sized,
+                    Self: Sized,
+                    Neglect: SafeCastOptions
+                {
+                    unsafe { TryCastFrom::<_,Neglect>::unsafe_try_cast_from(src) }
+                }
+
+                /// Instantiate `Self` by casting a value of type `Src`, potentially unsafely, if it is a valid source for the cast.
+                unsafe fn unsafe_try_cast_from(src: Src) -> Result<Self, CastError<Src>>
+                where
+                    Src: Sized,
+                    Self: Sized,
+                    Neglect: CastOptions;
+            }
+
+            /// The error produced when a fallible cast fails.
+            ///
+            /// The rejected source value may be recovered with [CastError::into_src].
+            pub struct CastError<Src> {
+                src: Src,
+                kind: CastErrorKind,
+            }
+
+            /// Why a fallible cast failed.
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #[non_exhaustive]
+            pub enum CastErrorKind {
+                /// The source is not sufficiently aligned for the destination type.
+                Misaligned,
+                /// The source has bytes left over that do not constitute a whole instance of the destination type.
+                TrailingBytes,
+                /// The source is not a valid instance of the destination type.
+                Invalid,
+            }
+
+            impl<Src> CastError<Src> {
+                /// Produce an error of the given kind, returning `src`.
+                pub fn new(src: Src, kind: CastErrorKind) -> Self {
+                    CastError { src, kind }
+                }
+
+                /// Why the cast failed.
+                pub fn kind(&self) -> CastErrorKind {
+                    self.kind
+                }
+
+                /// Produce the source value whose cast failed.
+                pub fn into_src(self) -> Src {
+                    self.src
+                }
+            }
+
+            impl<Src> core::fmt::Debug for CastError<Src> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_struct("CastError").field("kind", &self.kind).finish()
+                }
+            }
+
+            impl<Src> core::fmt::Display for CastError<Src> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str(match self.kind {
+                        CastErrorKind::Misaligned => "source is not sufficiently aligned for the destination type",
+                        CastErrorKind::TrailingBytes => "source has bytes left over that do not constitute a whole instance of the destination type",
+                        CastErrorKind::Invalid => "source is not a bit-valid instance of the destination type",
+                    })
+                }
+            }
+
+            /// Options for casting.
+            ///
+            /// With the `nightly-marker-traits` feature, [SafeCastOptions] and [CastOptions] are `#[marker]` traits, so each kind of cast (e.g., [slice casting][crate::core::slice::SliceCastOptions]) may extend them with its own options. Without it, every [TransmuteOptions][crate::core::convert::transmute::options::TransmuteOptions] is a [CastOptions], and every [SafeTransmuteOptions][crate::core::convert::transmute::options::SafeTransmuteOptions] is a [SafeCastOptions].
+            pub mod options {
+                #[cfg(not(feature = "nightly-marker-traits"))]
+                use crate::core::convert::transmute::options::{SafeTransmuteOptions, TransmuteOptions};
+
+                /// The super-trait of all *safe* casting options.
+                #[cfg_attr(feature = "nightly-marker-traits", marker)]
+                pub trait SafeCastOptions: CastOptions {}
+
+                /// The super-trait of all casting options.
+                #[cfg_attr(feature = "nightly-marker-traits", marker)]
+                pub trait CastOptions {}
+
+                #[cfg(feature = "nightly-marker-traits")]
+                impl SafeCastOptions for () {}
+                #[cfg(feature = "nightly-marker-traits")]
+                impl CastOptions for () {}
+
+                #[cfg(not(feature = "nightly-marker-traits"))]
+                impl<Neglect: SafeTransmuteOptions> SafeCastOptions for Neglect {}
+                #[cfg(not(feature = "nightly-marker-traits"))]
+                impl<Neglect: TransmuteOptions> CastOptions for Neglect {}
+
+                /// **🌟** Convert the integers of the source from byte order `O` into native byte order.
+                ///
+                /// Unlike the options of [transmutation][crate::core::convert::transmute::options], which neglect static checks, `SwapBytes` alters the bits of the cast value. For instance, to decode a big-endian table of `u32`s:
+                /// ```rust
+                /// use rfc::core::convert::{
+                ///     cast::{CastFrom, options::SwapBytes},
+                ///     transmute::byteorder::BigEndian,
+                /// };
+                ///
+                /// let src : &[u8] = &[0, 0, 0, 1, 0, 0, 0, 2][..];
+                /// let dst : Vec<u32> = CastFrom::<_, SwapBytes<BigEndian>>::cast_from(src);
+                /// assert_eq!(dst, [1, 2]);
+                /// ```
+                /// If `O` is the [NativeEndian][crate::core::convert::transmute::byteorder::NativeEndian] byte order, no bytes are swapped. See the [`&[u8]` **🠮** `Vec<Dst>` cast][super::CastFrom#impl-CastFrom<%26%27i%20%5Bu8%5D%2C%20SwapBytes<O>>-for-Vec<Dst>] for details.
+                #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+                pub struct SwapBytes<O: ByteOrder>(PhantomData<O>);
+
+                use crate::core::convert::transmute::byteorder::ByteOrder;
+                use core::marker::PhantomData;
+
+                impl<O: ByteOrder> SafeCastOptions for SwapBytes<O> {}
+                impl<O: ByteOrder> CastOptions for SwapBytes<O> {}
+            }
+        }
+
+    }
+
+    #[allow(unused_imports)] // for intra-doc links
+    use convert::transmute::*;
+
+    /// Basic functions for dealing with memory.
+    pub mod mem {
+        #[doc(no_inline)]
+        pub use core::mem::*;
+
+        #[allow(hidden_glob_reexports)] // the mock `TransmuteFrom` deliberately shadows libcore's
+        use crate::core::convert::transmute::{TransmuteFrom, options::*};
+
+        /// Implemented if `align_of::<Self>() <= align_of::<Rhs>()`
+        ///
+        /// Like every query in this module, `AlignLtEq` is derived from a transmutation under the options `Neglect`. [NeglectStability] permits querying types that make no stability promises; [NeglectAlignment] makes this query vacuous.
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        pub trait AlignLtEq<Rhs, Neglect=()>
+        where
+            Neglect: TransmuteOptions,
+        {}
+
+        /// By wrapping a type in a zero-sized array, we neutralize its validity and size qualities. The only quality by which `[Lhs; 0]` and `[Dst; 0]` can differ is their alignment. We check *only* if the alignment of `Lhs` is less than `Rhs` by transmuting between references of these zero-sized gadgets.
+        impl<Lhs, Rhs, Neglect> AlignLtEq<Rhs, Neglect> for Lhs
+        where
+            Neglect: TransmuteOptions,
+            for<'a> &'a [Lhs; 0]: TransmuteFrom<&'a [Rhs; 0], Neglect>
+        {}
+
+        /// Implemented if `align_of::<Self>() == align_of::<Rhs>()`
+        ///
+        /// See the [`Vec` casting demonstration][super::convert::cast::CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] for an example of its use.
+        ///
+        /// `AlignEq<Rhs, Neglect>` holds wherever the gadget transmutations underlying [AlignLtEq] hold in both directions under the same `Neglect`; e.g., for a type that makes no stability promises, under [NeglectStability]:
+        /// ```rust
+        /// use rfc::core::convert::transmute::options::NeglectStability;
+        /// use rfc::core::mem::AlignEq;
+        ///
+        /// #[repr(C)]
+        /// pub struct Unpromised(pub u8, pub u16);
+        ///
+        /// fn query<Lhs: AlignEq<Rhs, NeglectStability>, Rhs>() {}
+        ///
+        /// query::<Unpromised, Unpromised>();
+        /// ```
+        /// But not under the default options, which require that both types promise stability:
+        /// ```compile_fail,E0277
+        /// use rfc::core::mem::AlignEq;
+        ///
+        /// #[repr(C)]
+        /// pub struct Unpromised(pub u8, pub u16);
+        ///
+        /// fn query<Lhs: AlignEq<Rhs>, Rhs>() {}
+        ///
+        /// query::<Unpromised, Unpromised>();
+        /// // error[E0277]: the trait bound `Unpromised: AlignEq<Unpromised>` is not satisfied
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        pub trait AlignEq<Rhs, Neglect=()>
+        where
+            Neglect: TransmuteOptions,
+        {}
+
+        /// See [AlignLtEq].
+        impl<Lhs, Rhs, Neglect> AlignEq<Rhs, Neglect> for Lhs
+        where
+            Neglect: TransmuteOptions,
+            Lhs: AlignLtEq<Rhs, Neglect>,
+            Rhs: AlignLtEq<Lhs, Neglect>,
+        {}
+
+        mod private {
+            use core::mem::MaybeUninit;
+
+            // The alignment of this struct is always equal to `max(align_of::<A>(), align_of::<T>())`.
+            // Its validity is always equal to `MaybeUninit<T>`.
+            // Its size equals `T`
+            /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+            #[repr(C)]
+            pub struct Aligned<A, T>(pub [A; 0], pub MaybeUninit<T>);
+
+            // The alignment of this struct is always equal to `max(align_of::<A>(), align_of::<T>())`.
+            // Its validity and size equal `T`.
+            #[repr(C)]
+            pub struct AlignedAs<A, T>(pub [A; 0], pub T);
+
+            // A `Field`, preceded by `OFFSET` bytes of any content whatsoever.
+            // Its alignment is `1`, so `Field` begins at exactly `OFFSET`.
+            #[repr(C, packed)]
+            pub struct At<const OFFSET: usize, Field>(pub [MaybeUninit<u8>; OFFSET], pub Field);
+
+            use crate::core::convert::transmute::options::*;
+
+            // The options `Self`, with `NeglectValidity` added.
+            pub trait AndNeglectValidity: TransmuteOptions {
+                type Output: TransmuteOptions;
+            }
+
+            macro_rules! and_neglect_validity {
+                ($($Neglect:ty => $Output:ty;)*) => {$(
+                    impl AndNeglectValidity for $Neglect { type Output = $Output; }
+                )*};
+            }
+
+            and_neglect_validity! {
+                () => NeglectValidity;
+                NeglectStability => (NeglectStability, NeglectValidity);
+                NeglectAlignment => (NeglectAlignment, NeglectValidity);
+                NeglectValidity => Self;
+
+                (NeglectStability, NeglectAlignment) => (NeglectStability, NeglectAlignment, NeglectValidity);
+                (NeglectAlignment, NeglectStability) => (NeglectAlignment, NeglectStability, NeglectValidity);
+                (NeglectStability, NeglectValidity) => Self;
+                (NeglectValidity, NeglectStability) => Self;
+                (NeglectAlignment, NeglectValidity) => Self;
+                (NeglectValidity, NeglectAlignment) => Self;
+
+                (NeglectStability, NeglectAlignment, NeglectValidity) => Self;
+                (NeglectStability, NeglectValidity, NeglectAlignment) => Self;
+                (NeglectAlignment, NeglectStability, NeglectValidity) => Self;
+                (NeglectAlignment, NeglectValidity, NeglectStability) => Self;
+                (NeglectValidity, NeglectStability, NeglectAlignment) => Self;
+                (NeglectValidity, NeglectAlignment, NeglectStability) => Self;
+            }
+        }
+
+        /// Implemented if `size_of::<Self>() <= size_of::<Rhs>()`
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        pub trait SizeLtEq<Rhs, Neglect=()>
+        where
+            Neglect: TransmuteOptions,
+        {}
+
+        /// We wrap the types in a struct that neutralizes their alignment and validity differences, leaving size as the only quality that might differ between `Aligned<Rhs, Lhs>` and `Aligned<Lhs, Rhs>`.
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        impl<Lhs, Rhs, Neglect> SizeLtEq<Rhs, Neglect> for Lhs
+        where
+            Neglect: TransmuteOptions,
+            for<'a> &'a private::Aligned<Rhs, Lhs>: TransmuteFrom<&'a private::Aligned<Lhs, Rhs>, Neglect>,
+        {}
+
+        /// Implemented if `size_of::<Self>() == size_of::<Rhs>()`
+        ///
+        /// See the [`Box` casting demonstration][super::convert::cast::CastFrom#impl-CastFrom<Box<Src>%2C%20Neglect>-for-Box<Dst>] for an example of its use.
+        ///
+        /// `SizeEq<Rhs, Neglect>` holds wherever [SizeLtEq] holds in both directions under the same `Neglect`; e.g., for a type that makes no stability promises, under [NeglectStability]:
+        /// ```rust
+        /// use rfc::core::convert::transmute::options::NeglectStability;
+        /// use rfc::core::mem::SizeEq;
+        ///
+        /// #[repr(C)]
+        /// pub struct Unpromised(pub u8, pub u16);
+        ///
+        /// fn query<Lhs: SizeEq<Rhs, NeglectStability>, Rhs>() {}
+        ///
+        /// query::<Unpromised, Unpromised>();
+        /// ```
+        /// So the [`Vec` cast][super::convert::cast::CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] is usable with such types:
+        /// ```rust
+        /// use rfc::core::convert::{cast::CastFrom, transmute::options::NeglectStability};
+        ///
+        /// #[repr(C)]
+        /// pub struct Unpromised(pub u8, pub u16);
+        ///
+        /// let src : Vec<Unpromised> = vec![Unpromised(1, 2), Unpromised(3, 4)];
+        /// let dst : Vec<Unpromised> = CastFrom::<_, NeglectStability>::cast_from(src);
+        /// assert_eq!(dst.len(), 2);
+        /// ```
+        /// But neither holds under the default options, which require that both types promise stability:
+        /// ```compile_fail,E0277
+        /// use rfc::core::mem::SizeEq;
+        ///
+        /// #[repr(C)]
+        /// pub struct Unpromised(pub u8, pub u16);
+        ///
+        /// fn query<Lhs: SizeEq<Rhs>, Rhs>() {}
+        ///
+        /// query::<Unpromised, Unpromised>();
+        /// // error[E0277]: the trait bound `Unpromised: SizeEq<Unpromised>` is not satisfied
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        pub trait SizeEq<Rhs, Neglect=()>
+        where
+            Neglect: TransmuteOptions,
+        {}
+
+        /// See [SizeLtEq].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        impl<Lhs, Rhs, Neglect> SizeEq<Rhs, Neglect> for Lhs
+        where
+            Neglect: TransmuteOptions,
+            Lhs: SizeLtEq<Rhs, Neglect>,
+            Rhs: SizeLtEq<Lhs, Neglect>,
+        {}
+
+        /// Implemented if every byte of every valid instance of `Self` is initialized.
+        ///
+        /// This is the bound that serialization code ordinarily needs: a `NoUninitBytes` type may be viewed as a `[u8]`. It precludes padding, and also fields, like [MaybeUninit], whose bytes *may* be uninitialized:
+        /// ```rust,ignore
+        /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+        /// #[repr(C)]
+        /// pub struct Header {
+        ///     pub tag: u32,
+        ///     pub len: u32,
+        /// }
+        ///
+        /// fn write_all<W: Write, T: NoUninitBytes>(w: &mut W, values: &[T]) -> io::Result<()> {
+        ///     w.write_all(values.cast_into())
+        /// }
+        /// ```
+        /// But not for types with padding:
+        /// ```compile_fail,E0277
+        /// use rfc::core::mem::NoUninitBytes;
+        ///
+        /// fn query<T: NoUninitBytes>() {}
+        /// query::<(u8, u16)>();
+        /// // error[E0277]: the trait bound `(u8, u16): NoUninitBytes` is not satisfied
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        pub trait NoUninitBytes<Neglect=()>
+        where
+            Neglect: TransmuteOptions,
+        {}
+
+        /// A stream of `u8`s is transmutable from a stream of `Lhs`s only if every byte of every `Lhs` is initialized. Since `u8` has the least possible alignment, alignment plays no part.
+        impl<Lhs, Neglect> NoUninitBytes<Neglect> for Lhs
+        where
+            Neglect: TransmuteOptions,
+            for<'a> &'a [u8; usize::MAX]: TransmuteFrom<&'a [Lhs; usize::MAX], Neglect>,
+        {}
+
+        /// Implemented if no byte of `Self` is padding; i.e., if no byte of `Self` is uninitialized in *every* valid instance of `Self`.
+        ///
+        /// Unlike [NoUninitBytes], this admits bytes that *might* be uninitialized, such as those of a [MaybeUninit] field, or of the unused bytes of a smaller enum variant:
+        /// ```rust,ignore
+        /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+        /// #[repr(C)]
+        /// pub struct Slot {
+        ///     pub occupied: u32,
+        ///     pub value: MaybeUninit<u32>,
+        /// }
+        ///
+        /// assert_padding_free!(Slot);
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        pub trait IsPaddingFree<Neglect=()>
+        where
+            Neglect: TransmuteOptions,
+        {}
+
+        /// Under [NeglectValidity], only those transmutations that *cannot possibly* be valid are rejected. A stream of `u8`s is transmutable from a stream of `Lhs`s, neglecting validity, unless some byte of `Lhs` is *never* initialized.
+        impl<Lhs, Neglect> IsPaddingFree<Neglect> for Lhs
+        where
+            Neglect: private::AndNeglectValidity,
+            for<'a> &'a [u8; usize::MAX]: TransmuteFrom<&'a [Lhs; usize::MAX], <Neglect as private::AndNeglectValidity>::Output>,
+        {}
+
+        /// Implemented if every initialized sequence of `size_of::<Self>()` bytes is a valid instance of `Self`.
+        ///
+        /// This generalizes [FromBytes][super::convert::transmute::FromBytes] to any `Neglect`. E.g., `u32` and `[i8; 3]` are `AllBitPatternsValid`, but `bool`, `char` and [NonZeroU8][core::num::NonZeroU8] are not:
+        /// ```compile_fail,E0277
+        /// use rfc::core::mem::AllBitPatternsValid;
+        ///
+        /// fn query<T: AllBitPatternsValid>() {}
+        /// query::<bool>();
+        /// // error[E0277]: the trait bound `bool: AllBitPatternsValid` is not satisfied
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        pub trait AllBitPatternsValid<Neglect=()>
+        where
+            Neglect: TransmuteOptions,
+        {}
+
+        /// A single `Lhs` read from a stream of bytes is valid only if every byte sequence is a valid `Lhs`. Reading a single `Lhs`, in the manner of the [slice casts][crate::core::slice], never increases the size of the referent. We align the stream of bytes to `Lhs` so that, for any `Neglect`, alignment plays no part.
+        impl<Lhs, Neglect> AllBitPatternsValid<Neglect> for Lhs
+        where
+            Neglect: TransmuteOptions,
+            for<'a> &'a [Lhs; 1]: TransmuteFrom<&'a private::AlignedAs<Lhs, [u8; usize::MAX]>, Neglect>,
+        {}
+
+        /// Implemented if a valid `Field` lies at byte offset `OFFSET` of every valid instance of `Self`.
+        ///
+        /// The RFC's layout model identifies fields only by the bytes they occupy, so this query concerns offsets and types rather than names; the offset of a *named* field is given by [offset_of!]. Together, they check a field's layout at compile time:
+        /// ```rust,ignore
+        /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+        /// #[repr(C)]
+        /// pub struct Header {
+        ///     pub tag: u32,
+        ///     pub len: u32,
+        /// }
+        ///
+        /// assert_field_offset!(Header, u32, 4);
+        /// const _: () = assert!(offset_of!(Header, len) == 4);
+        /// ```
+        /// The field must lie within `Self`:
+        /// ```compile_fail,E0277
+        /// use rfc::core::mem::FieldOffset;
+        ///
+        /// fn query<T: FieldOffset<u32, 4>>() {}
+        /// query::<u32>();
+        /// // error[E0277]: the trait bound `u32: FieldOffset<u32, 4>` is not satisfied
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        pub trait FieldOffset<Field, const OFFSET: usize, Neglect=()>
+        where
+            Neglect: TransmuteOptions,
+        {}
+
+        /// A reference to `Lhs` is transmutable into a reference to `At<OFFSET, Field>` only if `Lhs` is at least `OFFSET + size_of::<Field>()` bytes long, and its bytes at `OFFSET` are a valid `Field`. `At` has an alignment of `1`, so alignment plays no part.
+        impl<Lhs, Field, const OFFSET: usize, Neglect> FieldOffset<Field, OFFSET, Neglect> for Lhs
+        where
+            Neglect: TransmuteOptions,
+            for<'a> &'a private::At<OFFSET, Field>: TransmuteFrom<&'a Lhs, Neglect>,
+        {}
+
+        /// **🌟** View the bytes of `value`, in a `const` context.
+        ///
+        /// The `const` counterpart of the [NoUninitBytes] query: it may only be called where the query holds, and may, unlike [AsBytes][super::convert::transmute::AsBytes], be called before `const` trait impls are stabilized. E.g., to view a value as bytes at compile time:
+        /// ```rust
+        /// use rfc::core::{convert::transmute::options::NeglectStability, mem::bytes_of};
+        ///
+        /// const BYTES: &[u8] = bytes_of::<u8, NeglectStability>(&0x2A);
+        /// const _: () = assert!(BYTES.len() == 1 && BYTES[0] == 0x2A);
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[inline(always)]
+        pub const fn bytes_of<T, Neglect>(value: &T) -> &[u8]
+        where
+            T: NoUninitBytes<Neglect>,
+            Neglect: SafeTransmuteOptions,
+        {
+            // Safe, because every byte of `T` is initialized, and `u8` has the least possible alignment.
+            unsafe { core::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+        }
+
+        /// **🌟** Instantiate a `T` from `bytes`, if `bytes` is exactly `size_of::<T>()` bytes long, in a `const` context.
+        ///
+        /// The `const` counterpart of the [AllBitPatternsValid] query, and of [FromBytes][super::convert::transmute::FromBytes]; e.g., with the compiler's implementation of [TransmuteFrom]:
+        /// ```rust,ignore
+        /// const WORD: Option<u32> = read_bytes::<u32, ()>(&[1, 0, 0, 0]);
+        /// const _: () = assert!(matches!(WORD, Some(word) if word == u32::from_le_bytes([1, 0, 0, 0])));
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[inline(always)]
+        pub const fn read_bytes<T, Neglect>(bytes: &[u8]) -> Option<T>
+        where
+            T: AllBitPatternsValid<Neglect>,
+            Neglect: SafeTransmuteOptions,
+        {
+            if bytes.len() != size_of::<T>() {
+                return None;
+            }
+            // Safe, because `bytes` is the size of `T`, every sequence of bytes is a valid `T`, and the read does not require alignment.
+            Some(unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const T) })
+        }
+
+        /// **🌟** Read the `Field` at byte offset `OFFSET` of `value`, in a `const` context.
+        ///
+        /// The `const` counterpart of the [FieldOffset] query; e.g., with the compiler's implementation of [TransmuteFrom]:
+        /// ```rust,ignore
+        /// const HEADER: Header = Header { tag: 7, len: 64 };
+        /// const LEN: u32 = read_field::<Header, u32, 4, ()>(&HEADER);
+        /// const _: () = assert!(LEN == 64);
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[inline(always)]
+        pub const fn read_field<T, Field, const OFFSET: usize, Neglect>(value: &T) -> Field
+        where
+            T: FieldOffset<Field, OFFSET, Neglect>,
+            Field: Copy,
+            Neglect: SafeTransmuteOptions,
+        {
+            // Safe, because the bytes of `value` at `OFFSET` are a valid `Field`, which is `Copy`, and the read does not require alignment.
+            unsafe { core::ptr::read_unaligned((value as *const T as *const u8).add(OFFSET) as *const Field) }
+        }
+
+        #[doc(inline)]
+        pub use crate::{assert_size_eq, assert_size_le, assert_align_eq, assert_align_le, assert_no_uninit_bytes, assert_padding_free, assert_all_bit_patterns_valid, assert_field_offset};
+
+        /// Assert, at compile time, that `size_of::<Lhs>() == size_of::<Rhs>()`.
+        ///
+        /// Expands to a `const` item, and so may be placed wherever an item may be; e.g., beside the definition of an FFI type:
+        /// ```rust,ignore
+        /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+        /// #[repr(C)]
+        /// pub struct timeval {
+        ///     pub tv_sec: i64,
+        ///     pub tv_usec: i64,
+        /// }
+        ///
+        /// assert_size_eq!(timeval, [u64; 2]);
+        /// assert_align_eq!(timeval, u64);
+        /// ```
+        /// An optional third argument supplies the [options][super::convert::transmute::options] under which the query is made; e.g., to assert the size of a type that makes no stability promises:
+        /// ```rust,ignore
+        /// assert_size_eq!(libc::timeval, [u64; 2], NeglectStability);
+        /// ```
+        /// For instance:
+        /// ```rust
+        /// use rfc::core::{convert::transmute::options::NeglectStability, mem::assert_size_eq};
+        ///
+        /// assert_size_eq!(u32, u32, NeglectStability);
+        /// ```
+        /// If the sizes differ, compilation fails:
+        /// ```compile_fail,E0277
+        /// use rfc::core::{convert::transmute::options::NeglectStability, mem::assert_size_eq};
+        ///
+        /// assert_size_eq!(u32, u64, NeglectStability);
+        /// // error[E0277]: the trait bound `u32: SizeEq<u64, NeglectStability>` is not satisfied
+        /// //   required by a bound in `size_of_lhs_must_equal_size_of_rhs`
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[macro_export]
+        macro_rules! assert_size_eq {
+            ($Lhs:ty, $Rhs:ty $(,)?) => {
+                $crate::assert_size_eq!($Lhs, $Rhs, ());
+            };
+            ($Lhs:ty, $Rhs:ty, $Neglect:ty $(,)?) => {
+                const _: () = {
+                    fn size_of_lhs_must_equal_size_of_rhs<Lhs, Rhs, Neglect>()
+                    where
+                        Neglect: $crate::core::convert::transmute::options::TransmuteOptions,
+                        Lhs: $crate::core::mem::SizeEq<Rhs, Neglect>,
+                    {}
+                    let _ = size_of_lhs_must_equal_size_of_rhs::<$Lhs, $Rhs, $Neglect>;
+                };
+            };
+        }
+
+        /// Assert, at compile time, that `size_of::<Lhs>() <= size_of::<Rhs>()`.
+        ///
+        /// See [assert_size_eq!].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[macro_export]
+        macro_rules! assert_size_le {
+            ($Lhs:ty, $Rhs:ty $(,)?) => {
+                $crate::assert_size_le!($Lhs, $Rhs, ());
+            };
+            ($Lhs:ty, $Rhs:ty, $Neglect:ty $(,)?) => {
+                const _: () = {
+                    fn size_of_lhs_must_not_exceed_size_of_rhs<Lhs, Rhs, Neglect>()
+                    where
+                        Neglect: $crate::core::convert::transmute::options::TransmuteOptions,
+                        Lhs: $crate::core::mem::SizeLtEq<Rhs, Neglect>,
+                    {}
+                    let _ = size_of_lhs_must_not_exceed_size_of_rhs::<$Lhs, $Rhs, $Neglect>;
+                };
+            };
+        }
+
+        /// Assert, at compile time, that `align_of::<Lhs>() == align_of::<Rhs>()`.
+        ///
+        /// See [assert_size_eq!].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[macro_export]
+        macro_rules! assert_align_eq {
+            ($Lhs:ty, $Rhs:ty $(,)?) => {
+                $crate::assert_align_eq!($Lhs, $Rhs, ());
+            };
+            ($Lhs:ty, $Rhs:ty, $Neglect:ty $(,)?) => {
+                const _: () = {
+                    fn align_of_lhs_must_equal_align_of_rhs<Lhs, Rhs, Neglect>()
+                    where
+                        Neglect: $crate::core::convert::transmute::options::TransmuteOptions,
+                        Lhs: $crate::core::mem::AlignEq<Rhs, Neglect>,
+                    {}
+                    let _ = align_of_lhs_must_equal_align_of_rhs::<$Lhs, $Rhs, $Neglect>;
+                };
+            };
+        }
+
+        /// Assert, at compile time, that `align_of::<Lhs>() <= align_of::<Rhs>()`.
+        ///
+        /// For instance:
+        /// ```rust
+        /// use rfc::core::{convert::transmute::options::NeglectStability, mem::assert_align_le};
+        ///
+        /// assert_align_le!(u32, u32, NeglectStability);
+        /// ```
+        /// ```compile_fail,E0277
+        /// use rfc::core::{convert::transmute::options::NeglectStability, mem::assert_align_le};
+        ///
+        /// assert_align_le!(u32, u8, NeglectStability);
+        /// // error[E0277]: the trait bound `u32: AlignLtEq<u8, NeglectStability>` is not satisfied
+        /// //   required by a bound in `align_of_lhs_must_not_exceed_align_of_rhs`
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[macro_export]
+        macro_rules! assert_align_le {
+            ($Lhs:ty, $Rhs:ty $(,)?) => {
+                $crate::assert_align_le!($Lhs, $Rhs, ());
+            };
+            ($Lhs:ty, $Rhs:ty, $Neglect:ty $(,)?) => {
+                const _: () = {
+                    fn align_of_lhs_must_not_exceed_align_of_rhs<Lhs, Rhs, Neglect>()
+                    where
+                        Neglect: $crate::core::convert::transmute::options::TransmuteOptions,
+                        Lhs: $crate::core::mem::AlignLtEq<Rhs, Neglect>,
+                    {}
+                    let _ = align_of_lhs_must_not_exceed_align_of_rhs::<$Lhs, $Rhs, $Neglect>;
+                };
+            };
+        }
+
+        /// Assert, at compile time, that every byte of every valid `T` is initialized.
+        ///
+        /// See [NoUninitBytes].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[macro_export]
+        macro_rules! assert_no_uninit_bytes {
+            ($T:ty $(,)?) => {
+                $crate::assert_no_uninit_bytes!($T, ());
+            };
+            ($T:ty, $Neglect:ty $(,)?) => {
+                const _: () = {
+                    fn every_byte_of_t_must_be_initialized<T, Neglect>()
+                    where
+                        Neglect: $crate::core::convert::transmute::options::TransmuteOptions,
+                        T: $crate::core::mem::NoUninitBytes<Neglect>,
+                    {}
+                    let _ = every_byte_of_t_must_be_initialized::<$T, $Neglect>;
+                };
+            };
+        }
+
+        /// Assert, at compile time, that `T` has no padding.
+        ///
+        /// See [IsPaddingFree].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[macro_export]
+        macro_rules! assert_padding_free {
+            ($T:ty $(,)?) => {
+                $crate::assert_padding_free!($T, ());
+            };
+            ($T:ty, $Neglect:ty $(,)?) => {
+                const _: () = {
+                    fn t_must_be_padding_free<T, Neglect>()
+                    where
+                        Neglect: $crate::core::convert::transmute::options::TransmuteOptions,
+                        T: $crate::core::mem::IsPaddingFree<Neglect>,
+                    {}
+                    let _ = t_must_be_padding_free::<$T, $Neglect>;
+                };
+            };
+        }
+
+        /// Assert, at compile time, that every initialized sequence of `size_of::<T>()` bytes is a valid `T`.
+        ///
+        /// See [AllBitPatternsValid].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[macro_export]
+        macro_rules! assert_all_bit_patterns_valid {
+            ($T:ty $(,)?) => {
+                $crate::assert_all_bit_patterns_valid!($T, ());
+            };
+            ($T:ty, $Neglect:ty $(,)?) => {
+                const _: () = {
+                    fn every_bit_pattern_must_be_a_valid_t<T, Neglect>()
+                    where
+                        Neglect: $crate::core::convert::transmute::options::TransmuteOptions,
+                        T: $crate::core::mem::AllBitPatternsValid<Neglect>,
+                    {}
+                    let _ = every_bit_pattern_must_be_a_valid_t::<$T, $Neglect>;
+                };
+            };
+        }
+
+        /// Assert, at compile time, that a valid `Field` lies at byte offset `OFFSET` of every valid `T`.
+        ///
+        /// See [FieldOffset].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "query", issue = "none"))]
+        #[macro_export]
+        macro_rules! assert_field_offset {
+            ($T:ty, $Field:ty, $OFFSET:expr $(,)?) => {
+                $crate::assert_field_offset!($T, $Field, $OFFSET, ());
+            };
+            ($T:ty, $Field:ty, $OFFSET:expr, $Neglect:ty $(,)?) => {
+                const _: () = {
+                    fn field_must_lie_at_offset<T, Field, const OFFSET: usize, Neglect>()
+                    where
+                        Neglect: $crate::core::convert::transmute::options::TransmuteOptions,
+                        T: $crate::core::mem::FieldOffset<Field, OFFSET, Neglect>,
+                    {}
+                    let _ = field_must_lie_at_offset::<$T, $Field, { $OFFSET }, $Neglect>;
+                };
+            };
+        }
+    }
+
+    /// A dynamically-sized view into a contiguous sequence, `[T]`.
+    pub mod slice {
+        #[doc(no_inline)]
+        pub use core::slice::*;
+
+        use crate::core::convert::{
+            transmute::{
+                TransmuteFrom,
+                TryTransmuteFrom,
+                options::{SafeTransmuteOptions, TransmuteOptions},
+            },
+            cast::{
+                CastFrom,
+                TryCastFrom,
+                CastError,
+                CastErrorKind,
+                options::{
+                    SafeCastOptions,
+                    CastOptions,
+                },
+            },
+        };
+
+        use core::{
+            mem::{align_of, size_of, size_of_val},
+            slice
+        };
+
+        /// **🌟** *Safe* options for casting **slices**.
+        ///
+        /// Slice casting transmutes the contents of the slice, and adjusts the slice's length as needed. All [SafeTransmuteOptions] are [SafeSliceCastOptions].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        pub trait SafeSliceCastOptions
+            : SafeCastOptions
+            + SafeTransmuteOptions
+            + SliceCastOptions
+        {}
+
+        /// **🌟** Options for casting **slices**.
+        ///
+        /// Slice casting transmutes the contents of the slice, and adjusts the slice's length as needed. All [TransmuteOptions] are [SliceCastOptions].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        pub trait SliceCastOptions
+            : CastOptions
+            + TransmuteOptions
+        {}
+
+        #[cfg(feature = "nightly-marker-traits")]
+        impl<Neglect: SafeSliceCastOptions> SafeCastOptions for Neglect {}
+        impl<Neglect: SafeTransmuteOptions> SafeSliceCastOptions for Neglect {}
+
+        #[cfg(feature = "nightly-marker-traits")]
+        impl<Neglect: SliceCastOptions> CastOptions for Neglect {}
+        impl<Neglect: TransmuteOptions> SliceCastOptions for Neglect {}
+
+        /// #### `&[Src]` **🠮** `&[Dst]`
+        /// If the sizes of `Src` and `Dst` differ, the length of the output slice is adjusted as-needed.
+        ///
+        /// ##### Static Options
+        /// See [SliceCastOptions] and [SafeSliceCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust,ignore
+        /// let src : &[i8] = &[-1, -2, -3, -4][..];
+        /// let dst : &[[u8; 2]] = src.cast_into();
+        /// assert_eq!(dst, &[[-255, -254], [-253, -252]][..]);
+        /// ```
+        impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i [Src], Neglect> for &'o [Dst]
+        where
+            Neglect: SliceCastOptions,
+            &'o [Dst; 1]: TransmuteFrom<&'i [Src; usize::MAX], Neglect>
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: &'i [Src]) -> &'o [Dst]
+            {
+                unsafe { unsafe_cast_slice::<Src, Dst, Neglect>(src) }
+            }
+        }
+
+        /// #### `&mut [Src]` **🠮** `&mut [Dst]`
+        /// If the sizes of `Src` and `Dst` differ, the length of the output slice is adjusted as-needed.
+        ///
+        /// ##### Static Options
+        /// See [SliceCastOptions] and [SafeSliceCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust,ignore
         /// let src : &mut [i8] = &mut [-1, -2, -3, -4][..];
         /// let dst : &mut [[u8; 2]] = src.cast_into();
         /// assert_eq!(dst, &mut [[-255, -254], [-253, -252]][..]);
         /// ```
-        impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], Neglect> for &'o mut [Dst]
+        impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], Neglect> for &'o mut [Dst]
+        where
+            Neglect: SliceCastOptions,
+            &'o mut [Dst; 1]: TransmuteFrom<&'i mut [Src; usize::MAX], Neglect>
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> &'o mut [Dst]
+            {
+                let len = size_of_val(src).checked_div(size_of::<Dst>()).unwrap_or(0);
+                unsafe { slice::from_raw_parts_mut(src.as_ptr() as *mut Dst, len) }
+            }
+        }
+
+        /// #### `&mut [Src]` **🠮** `&[Dst]`
+        /// If the sizes of `Src` and `Dst` differ, the length of the output slice is adjusted as-needed.
+        ///
+        /// ##### Static Options
+        /// See [SliceCastOptions] and [SafeSliceCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust,ignore
+        /// let src : &mut [i8] = &mut [-1, -2, -3, -4][..];
+        /// let dst : &[[u8; 2]] = src.cast_into();
+        /// assert_eq!(dst, &[[-255, -254], [-253, -252]][..]);
+        /// ```
+        impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], Neglect> for &'o [Dst]
+        where
+            Neglect: SliceCastOptions,
+            &'o [Dst; 1]: TransmuteFrom<&'i mut [Src; usize::MAX], Neglect>
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> &'o [Dst]
+            {
+                let len = size_of_val(src).checked_div(size_of::<Dst>()).unwrap_or(0);
+                unsafe {
+                    slice::from_raw_parts(src.as_ptr() as *const Dst, len)
+                }
+            }
+        }
+
+        /// **🌟** Cast `&[Src]` into `&[Dst]`, safely, in a `const` context.
+        ///
+        /// The [slice cast][CastFrom#impl-CastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D] is implemented in terms of this function, which may, unlike `src.cast_into()`, be called before `const` trait impls are stabilized. E.g., to view a table of bytes as a table of words, at compile time:
+        /// ```rust,ignore
+        /// #[repr(C, align(4))]
+        /// struct Aligned([u8; 256]);
+        ///
+        /// const BYTES: &Aligned = &Aligned(*include_bytes!("table.bin"));
+        /// const TABLE: &[u32] = cast_slice::<u8, u32, ()>(&BYTES.0);
+        /// const _: () = assert!(TABLE.len() == 64);
+        /// ```
+        /// On stable, where the gadget underlying this cast is only stood in for with [NeglectAlignment][crate::core::convert::transmute::options::NeglectAlignment], the same may be done with [unsafe_cast_slice], so long as the bytes are sufficiently aligned:
+        /// ```rust
+        /// use rfc::core::{convert::transmute::options::NeglectAlignment, slice::unsafe_cast_slice};
+        ///
+        /// #[repr(C, align(4))]
+        /// struct Aligned([u8; 8]);
+        ///
+        /// const BYTES: &Aligned = &Aligned([0x01, 0x01, 0x01, 0x01, 0x02, 0x02, 0x02, 0x02]);
+        /// // Sound, because `Aligned` is aligned to `4`, and every 4 bytes are a valid `u32`.
+        /// const TABLE: &[u32] = unsafe { unsafe_cast_slice::<u8, u32, NeglectAlignment>(&BYTES.0) };
+        /// const _: () = assert!(TABLE.len() == 2);
+        /// const _: () = assert!(TABLE[0] == 0x0101_0101 && TABLE[1] == 0x0202_0202);
+        /// ```
+        /// [`Vec`][crate::std::vec] and the other owned casts have no `const` equivalent, as allocation cannot occur in a `const` context.
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        #[inline(always)]
+        pub const fn cast_slice<'i, 'o, Src, Dst, Neglect>(src: &'i [Src]) -> &'o [Dst]
+        where
+            Neglect: SafeSliceCastOptions,
+            &'o [Dst; 1]: TransmuteFrom<&'i [Src; usize::MAX], Neglect>
+        {
+            unsafe { unsafe_cast_slice::<Src, Dst, Neglect>(src) }
+        }
+
+        /// **🌟** Cast `&[Src]` into `&[Dst]`, potentially unsafely, in a `const` context.
+        ///
+        /// The onus is on you to ensure that calling this function is safe. See [cast_slice].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        #[inline(always)]
+        pub const unsafe fn unsafe_cast_slice<'i, 'o, Src, Dst, Neglect>(src: &'i [Src]) -> &'o [Dst]
+        where
+            Neglect: SliceCastOptions,
+            &'o [Dst; 1]: TransmuteFrom<&'i [Src; usize::MAX], Neglect>
+        {
+            // `Option::unwrap_or` is not `const`.
+            let len = match size_of_val(src).checked_div(size_of::<Dst>()) {
+                Some(len) => len,
+                None => 0,
+            };
+            unsafe { slice::from_raw_parts(src.as_ptr() as *const Dst, len) }
+        }
+
+        /// **🌟** Casts of the prefix or suffix of a slice, which return the remainder of the slice.
+        ///
+        /// The [slice casts][CastFrom#impl-CastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D] discard any trailing bytes of the source slice that do not constitute a whole `Dst`. These methods instead cast as many whole `Dst`s as possible from the start (or end) of the slice, and return the remaining `Src`s alongside them. If `size_of::<Dst>()` is not a multiple of `size_of::<Src>()`, the cast `Dst`s end (or begin) at the last (or first) `Src` boundary that is also a `Dst` boundary.
+        ///
+        /// In libcore, these would be inherent methods of `[Src]`.
+        ///
+        /// ##### Static Options
+        /// See [SliceCastOptions] and [SafeSliceCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust,ignore
+        /// let src : &[u8] = &[1, 2, 3, 4, 5][..];
+        ///
+        /// let (dst, rest) : (&[[u8; 2]], &[u8]) = src.cast_prefix::<_, ()>();
+        /// assert_eq!(dst, &[[1, 2], [3, 4]][..]);
+        /// assert_eq!(rest, &[5][..]);
+        ///
+        /// let (dst, rest) : (&[[u8; 2]], &[u8]) = src.cast_suffix::<_, ()>();
+        /// assert_eq!(dst, &[[2, 3], [4, 5]][..]);
+        /// assert_eq!(rest, &[1][..]);
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        pub trait CastSlice<Src> {
+            /// Cast as many whole `Dst`s as possible from the start of `self`, and return the remaining `Src`s.
+            fn cast_prefix<'a, Dst, Neglect>(&'a self) -> (&'a [Dst], &'a [Src])
+            where
+                &'a [Dst]: CastFrom<&'a [Src], Neglect>,
+                Neglect: SafeSliceCastOptions;
+
+            /// Cast as many whole `Dst`s as possible from the end of `self`, and return the preceding `Src`s.
+            fn cast_suffix<'a, Dst, Neglect>(&'a self) -> (&'a [Dst], &'a [Src])
+            where
+                &'a [Dst]: CastFrom<&'a [Src], Neglect>,
+                Neglect: SafeSliceCastOptions;
+
+            /// Cast as many whole `Dst`s as possible from the start of `self`, and return the remaining `Src`s; like [split_at_mut][slice::split_at_mut].
+            fn cast_prefix_mut<'a, Dst, Neglect>(&'a mut self) -> (&'a mut [Dst], &'a mut [Src])
+            where
+                &'a mut [Dst]: CastFrom<&'a mut [Src], Neglect>,
+                Neglect: SafeSliceCastOptions;
+
+            /// Cast as many whole `Dst`s as possible from the end of `self`, and return the preceding `Src`s; like [split_at_mut][slice::split_at_mut].
+            fn cast_suffix_mut<'a, Dst, Neglect>(&'a mut self) -> (&'a mut [Dst], &'a mut [Src])
+            where
+                &'a mut [Dst]: CastFrom<&'a mut [Src], Neglect>,
+                Neglect: SafeSliceCastOptions;
+        }
+
+        impl<Src> CastSlice<Src> for [Src] {
+            #[inline(always)]
+            fn cast_prefix<'a, Dst, Neglect>(&'a self) -> (&'a [Dst], &'a [Src])
+            where
+                &'a [Dst]: CastFrom<&'a [Src], Neglect>,
+                Neglect: SafeSliceCastOptions,
+            {
+                let (len, consumed) = cast_len::<Src, Dst>(self.len());
+                let (cast, rest) = self.split_at(consumed);
+                (unsafe { slice::from_raw_parts(cast.as_ptr() as *const Dst, len) }, rest)
+            }
+
+            #[inline(always)]
+            fn cast_suffix<'a, Dst, Neglect>(&'a self) -> (&'a [Dst], &'a [Src])
+            where
+                &'a [Dst]: CastFrom<&'a [Src], Neglect>,
+                Neglect: SafeSliceCastOptions,
+            {
+                let (len, consumed) = cast_len::<Src, Dst>(self.len());
+                let (rest, cast) = self.split_at(self.len() - consumed);
+                (unsafe { slice::from_raw_parts(cast.as_ptr() as *const Dst, len) }, rest)
+            }
+
+            #[inline(always)]
+            fn cast_prefix_mut<'a, Dst, Neglect>(&'a mut self) -> (&'a mut [Dst], &'a mut [Src])
+            where
+                &'a mut [Dst]: CastFrom<&'a mut [Src], Neglect>,
+                Neglect: SafeSliceCastOptions,
+            {
+                let (len, consumed) = cast_len::<Src, Dst>(self.len());
+                let (cast, rest) = self.split_at_mut(consumed);
+                (unsafe { slice::from_raw_parts_mut(cast.as_mut_ptr() as *mut Dst, len) }, rest)
+            }
+
+            #[inline(always)]
+            fn cast_suffix_mut<'a, Dst, Neglect>(&'a mut self) -> (&'a mut [Dst], &'a mut [Src])
+            where
+                &'a mut [Dst]: CastFrom<&'a mut [Src], Neglect>,
+                Neglect: SafeSliceCastOptions,
+            {
+                let (len, consumed) = cast_len::<Src, Dst>(self.len());
+                let at = self.len() - consumed;
+                let (rest, cast) = self.split_at_mut(at);
+                (unsafe { slice::from_raw_parts_mut(cast.as_mut_ptr() as *mut Dst, len) }, rest)
+            }
+        }
+
+        // Of `len` `Src`s, the number of whole `Dst`s that may be cast from them while ending on a `Src` boundary, and the number of `Src`s those `Dst`s consume.
+        #[inline(always)]
+        fn cast_len<Src, Dst>(len: usize) -> (usize, usize) {
+            fn gcd(a: usize, b: usize) -> usize {
+                if b == 0 { a } else { gcd(b, a % b) }
+            }
+
+            let (src_size, dst_size) = (size_of::<Src>(), size_of::<Dst>());
+            if src_size == 0 || dst_size == 0 {
+                return (0, 0);
+            }
+            // The number of `Dst`s must be a multiple of `step` to end on a `Src` boundary.
+            let step = src_size / gcd(src_size, dst_size);
+            let dst_len = (len * src_size / dst_size) / step * step;
+            (dst_len, dst_len * dst_size / src_size)
+        }
+
+        // Dynamically check that `src` may be reinterpreted as a slice of `Dst`, producing its length in `Dst`s.
+        #[inline(always)]
+        fn try_cast_len<Src, Dst, Neglect>(src: &[Src]) -> Result<usize, CastErrorKind>
+        where
+            Neglect: TransmuteOptions,
+            [Dst]: TryTransmuteFrom<[Src], Neglect>,
+        {
+            if !(src.as_ptr() as usize).is_multiple_of(align_of::<Dst>()) {
+                return Err(CastErrorKind::Misaligned);
+            }
+            let len = size_of_val(src).checked_div(size_of::<Dst>()).unwrap_or(0);
+            if len * size_of::<Dst>() != size_of_val(src) {
+                return Err(CastErrorKind::TrailingBytes);
+            }
+            if !<[Dst] as TryTransmuteFrom<[Src], Neglect>>::is_bit_valid(src) {
+                return Err(CastErrorKind::Invalid);
+            }
+            Ok(len)
+        }
+
+        /// #### `&[Src]` **🠮** `&[Dst]`, fallibly
+        /// Unlike the [infallible cast][CastFrom#impl-CastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D], the alignment of the slice, the absence of trailing bytes, and the validity of its contents are checked dynamically. The cast fails, returning the source slice, if:
+        ///  - the slice is not aligned to `align_of::<Dst>()` ([CastErrorKind::Misaligned]),
+        ///  - the size of the slice is not a multiple of `size_of::<Dst>()` ([CastErrorKind::TrailingBytes]), or
+        ///  - any `Dst` within it is invalid ([CastErrorKind::Invalid]).
+        ///
+        /// ##### Static Options
+        /// See [SliceCastOptions] and [SafeSliceCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::cast::{TryCastInto, CastErrorKind};
+        ///
+        /// let src : &[u8] = &[1, 0, 1][..];
+        /// let dst : &[bool] = src.try_cast_into().unwrap();
+        /// assert_eq!(dst, [true, false, true]);
+        ///
+        /// let src : &[u8] = &[1, 0, 2][..];
+        /// let err = TryCastInto::<&[bool]>::try_cast_into(src).unwrap_err();
+        /// assert_eq!(err.kind(), CastErrorKind::Invalid);
+        /// assert_eq!(err.into_src(), src);
+        ///
+        /// #[repr(C, align(4))]
+        /// struct Aligned<T>(T);
+        ///
+        /// let bytes = Aligned([('a' as u32).to_ne_bytes(), ('🌟' as u32).to_ne_bytes(), 0xD800_u32.to_ne_bytes()]);
+        /// let src : &[u8] = bytes.0.as_flattened();
+        ///
+        /// let dst : &[char] = src[..8].try_cast_into().unwrap();
+        /// assert_eq!(dst, ['a', '🌟']);
+        ///
+        /// let err = TryCastInto::<&[char]>::try_cast_into(&src[1..5]).unwrap_err();
+        /// assert_eq!(err.kind(), CastErrorKind::Misaligned);
+        ///
+        /// let err = TryCastInto::<&[char]>::try_cast_into(&src[..6]).unwrap_err();
+        /// assert_eq!(err.kind(), CastErrorKind::TrailingBytes);
+        ///
+        /// // `0xD800` is a surrogate, and so not a `char`.
+        /// let err = TryCastInto::<&[char]>::try_cast_into(src).unwrap_err();
+        /// assert_eq!(err.kind(), CastErrorKind::Invalid);
+        /// ```
+        impl<'i, 'o, Src, Dst, Neglect> TryCastFrom<&'i [Src], Neglect> for &'o [Dst]
+        where
+            'i: 'o,
+            Neglect: SliceCastOptions,
+            [Dst]: TryTransmuteFrom<[Src], Neglect>,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_try_cast_from(src: &'i [Src]) -> Result<&'o [Dst], CastError<&'i [Src]>>
+            {
+                match try_cast_len::<Src, Dst, Neglect>(src) {
+                    Ok(len) => Ok(unsafe { slice::from_raw_parts(src.as_ptr() as *const Dst, len) }),
+                    Err(kind) => Err(CastError::new(src, kind)),
+                }
+            }
+        }
+
+        /// #### `&mut [Src]` **🠮** `&mut [Dst]`, fallibly
+        /// As [`&[Src]` **🠮** `&[Dst]`][TryCastFrom#impl-TryCastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D]. Additionally, since any `Dst` may be written to the produced slice, every slice of `Dst` must be a valid slice of `Src`.
+        ///
+        /// ##### Static Options
+        /// See [SliceCastOptions] and [SafeSliceCastOptions].
+        impl<'i, 'o, Src, Dst, Neglect> TryCastFrom<&'i mut [Src], Neglect> for &'o mut [Dst]
+        where
+            'i: 'o,
+            Neglect: SliceCastOptions,
+            [Dst]: TryTransmuteFrom<[Src], Neglect>,
+            [Src]: TransmuteFrom<[Dst], Neglect>,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_try_cast_from(src: &'i mut [Src]) -> Result<&'o mut [Dst], CastError<&'i mut [Src]>>
+            {
+                match try_cast_len::<Src, Dst, Neglect>(src) {
+                    Ok(len) => Ok(unsafe { slice::from_raw_parts_mut(src.as_mut_ptr() as *mut Dst, len) }),
+                    Err(kind) => Err(CastError::new(src, kind)),
+                }
+            }
+        }
+
+        /// #### `&mut [Src]` **🠮** `&[Dst]`, fallibly
+        /// As [`&[Src]` **🠮** `&[Dst]`][TryCastFrom#impl-TryCastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D].
+        ///
+        /// ##### Static Options
+        /// See [SliceCastOptions] and [SafeSliceCastOptions].
+        impl<'i, 'o, Src, Dst, Neglect> TryCastFrom<&'i mut [Src], Neglect> for &'o [Dst]
+        where
+            'i: 'o,
+            Neglect: SliceCastOptions,
+            [Dst]: TryTransmuteFrom<[Src], Neglect>,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_try_cast_from(src: &'i mut [Src]) -> Result<&'o [Dst], CastError<&'i mut [Src]>>
+            {
+                match try_cast_len::<Src, Dst, Neglect>(src) {
+                    Ok(len) => Ok(unsafe { slice::from_raw_parts(src.as_ptr() as *const Dst, len) }),
+                    Err(kind) => Err(CastError::new(src, kind)),
+                }
+            }
+        }
+    }
+
+    /// String slices, `str`.
+    pub mod str {
+        #[doc(no_inline)]
+        pub use core::str::*;
+
+        use crate::core::convert::{
+            transmute::options::{
+                TransmuteOptions,
+                NeglectStability,
+                NeglectAlignment,
+                NeglectValidity,
+            },
+            cast::{
+                CastFrom,
+                TryCastFrom,
+                CastError,
+                CastErrorKind,
+                options::CastOptions,
+            },
+        };
+        use crate::core::slice::SliceCastOptions;
+
+        /// **🌟** Options for casting bytes into **strings**.
+        ///
+        /// That a `str` is UTF-8 is a library invariant which the compiler does not check: to the compiler, a `str` is just a `[u8]`. Casting bytes into a string is therefore only available with options that include [NeglectValidity], and only by the `unsafe_cast_*` methods; by using them, you are committing to ensure that the bytes are valid UTF-8. To have that validated dynamically instead, use the [fallible cast][TryCastFrom#impl-TryCastFrom<%26%27i%20%5Bu8%5D%2C%20Neglect>-for-%26%27o%20str].
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        pub trait StrCastOptions
+            : CastOptions
+            + TransmuteOptions
+        {}
+
+        impl StrCastOptions for NeglectValidity {}
+
+        impl StrCastOptions for (NeglectStability, NeglectValidity) {}
+        impl StrCastOptions for (NeglectValidity, NeglectStability) {}
+        impl StrCastOptions for (NeglectAlignment, NeglectValidity) {}
+        impl StrCastOptions for (NeglectValidity, NeglectAlignment) {}
+
+        impl StrCastOptions for (NeglectStability, NeglectAlignment, NeglectValidity) {}
+        impl StrCastOptions for (NeglectStability, NeglectValidity, NeglectAlignment) {}
+        impl StrCastOptions for (NeglectAlignment, NeglectStability, NeglectValidity) {}
+        impl StrCastOptions for (NeglectAlignment, NeglectValidity, NeglectStability) {}
+        impl StrCastOptions for (NeglectValidity, NeglectStability, NeglectAlignment) {}
+        impl StrCastOptions for (NeglectValidity, NeglectAlignment, NeglectStability) {}
+
+        /// #### `&[u8]` **🠮** `&str`
+        /// The bytes are not checked to be valid UTF-8.
+        ///
+        /// ##### Static Options
+        /// See [StrCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::{cast::CastInto, transmute::options::NeglectValidity};
+        ///
+        /// let src : &[u8] = b"hello";
+        /// // Safe, because `src` is valid UTF-8.
+        /// let dst : &str = unsafe { CastInto::<_, NeglectValidity>::unsafe_cast_into(src) };
+        /// assert_eq!(dst, "hello");
+        /// ```
+        /// The cast is not available without [NeglectValidity]:
+        /// ```compile_fail,E0277
+        /// use rfc::core::convert::cast::CastInto;
+        ///
+        /// let src : &[u8] = b"hello";
+        /// let dst : &str = CastInto::<&str>::cast_into(src);
+        /// // error[E0277]: the trait bound `(): StrCastOptions` is not satisfied
+        /// ```
+        impl<'i, 'o, Neglect> CastFrom<&'i [u8], Neglect> for &'o str
+        where
+            'i: 'o,
+            Neglect: StrCastOptions,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: &'i [u8]) -> &'o str
+            {
+                unsafe { from_utf8_unchecked(src) }
+            }
+        }
+
+        /// #### `&[u8]` **🠮** `&str`, fallibly
+        /// Unlike the [infallible cast][CastFrom#impl-CastFrom<%26%27i%20%5Bu8%5D%2C%20Neglect>-for-%26%27o%20str], the bytes are checked to be valid UTF-8. The cast fails with [CastErrorKind::Invalid], returning the source slice, if they are not.
+        ///
+        /// ##### Static Options
+        /// See [SliceCastOptions] and [SafeSliceCastOptions][crate::core::slice::SafeSliceCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::cast::{TryCastInto, CastErrorKind};
+        ///
+        /// let src : &[u8] = b"hello";
+        /// let dst : &str = TryCastInto::<&str>::try_cast_into(src).unwrap();
+        /// assert_eq!(dst, "hello");
+        ///
+        /// let src : &[u8] = &[0xC0, 0x80][..];
+        /// let err = TryCastInto::<&str>::try_cast_into(src).unwrap_err();
+        /// assert_eq!(err.kind(), CastErrorKind::Invalid);
+        /// assert_eq!(err.into_src(), src);
+        /// ```
+        impl<'i, 'o, Neglect> TryCastFrom<&'i [u8], Neglect> for &'o str
+        where
+            'i: 'o,
+            Neglect: SliceCastOptions,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_try_cast_from(src: &'i [u8]) -> Result<&'o str, CastError<&'i [u8]>>
+            {
+                from_utf8(src).map_err(|_| CastError::new(src, CastErrorKind::Invalid))
+            }
+        }
+
+        /// #### `&str` **🠮** `&[u8]`
+        /// Every string is a valid sequence of bytes, so this cast is safe with any [SafeSliceCastOptions][crate::core::slice::SafeSliceCastOptions].
+        ///
+        /// ##### Static Options
+        /// See [SliceCastOptions] and [SafeSliceCastOptions][crate::core::slice::SafeSliceCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::cast::CastInto;
+        ///
+        /// let src : &str = "hello";
+        /// let dst : &[u8] = CastInto::<&[u8]>::cast_into(src);
+        /// assert_eq!(dst, b"hello");
+        /// ```
+        impl<'i, 'o, Neglect> CastFrom<&'i str, Neglect> for &'o [u8]
+        where
+            'i: 'o,
+            Neglect: SliceCastOptions,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: &'i str) -> &'o [u8]
+            {
+                src.as_bytes()
+            }
+        }
+    }
+}
+
+/// Additions to `libstd`
+pub mod std {
+    /// Memory allocation APIs.
+    ///
+    /// ## Casting owned pointers
+    /// **🌟** The casts of [`Box`][crate::std::boxed], [`Rc`][crate::std::rc] and [`Arc`][crate::std::sync::Arc] reinterpret the pointee in place, and reconstitute the pointer with `from_raw`. Since the allocation will eventually be freed with the [Layout][::std::alloc::Layout] of `Dst`, that layout must be the one it was allocated with: the size and static alignment of `Src` and `Dst` must be equal. [AlignEq][crate::core::mem::AlignEq] and [SizeEq][crate::core::mem::SizeEq] enforce both invariants statically. They are queried under [NeglectStability][crate::core::convert::transmute::options::NeglectStability] alone, whatever the options of the cast, so that neither [NeglectAlignment][crate::core::convert::transmute::options::NeglectAlignment] nor any other option can relax them.
+    pub mod alloc {
+        #[doc(no_inline)]
+        pub use std::alloc::*;
+
+        use crate::core::convert::{
+            transmute::options::{SafeTransmuteOptions, TransmuteOptions},
+            cast::options::{
+                SafeCastOptions,
+                CastOptions,
+            },
+        };
+
+        /// **🌟** Safe options for [casting owned pointers][self#casting-owned-pointers]; i.e., `Box<T>` to `Box<U>`, and `Box<[T]>`, `Rc<[T]>` or `Arc<[T]>` to `Box<[U]>`, `Rc<[U]>` or `Arc<[U]>`.
+        ///
+        /// These casts transmute the contents of the allocation. All [SafeTransmuteOptions] are [SafeAllocCastOptions].
+        ///
+        /// See the [here][crate::core::convert::cast::CastFrom#impl-CastFrom<Box<%5BSrc%5D>%2C%20Neglect>-for-Box<%5BDst%5D>] for examples.
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        pub trait SafeAllocCastOptions
+            : SafeCastOptions
+            + SafeTransmuteOptions
+            + AllocCastOptions
+        {}
+
+        /// **🌟** Options for [casting owned pointers][self#casting-owned-pointers]; i.e., `Box<T>` to `Box<U>`, and `Box<[T]>`, `Rc<[T]>` or `Arc<[T]>` to `Box<[U]>`, `Rc<[U]>` or `Arc<[U]>`.
+        ///
+        /// These casts transmute the contents of the allocation. All [TransmuteOptions] are [AllocCastOptions].
+        ///
+        /// See the [here][crate::core::convert::cast::CastFrom#impl-CastFrom<Box<%5BSrc%5D>%2C%20Neglect>-for-Box<%5BDst%5D>] for examples.
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        pub trait AllocCastOptions
+            : TransmuteOptions
+            + CastOptions
+        {}
+
+        #[cfg(feature = "nightly-marker-traits")]
+        impl<Neglect: SafeAllocCastOptions> SafeCastOptions for Neglect {}
+        impl<Neglect: SafeTransmuteOptions> SafeAllocCastOptions for Neglect {}
+
+        #[cfg(feature = "nightly-marker-traits")]
+        impl<Neglect: AllocCastOptions> CastOptions for Neglect {}
+        impl<Neglect: TransmuteOptions> AllocCastOptions for Neglect {}
+    }
+
+    /// A contiguous growable array type with heap-allocated contents, `Vec<T>`.
+    pub mod vec {
+        #[doc(no_inline)]
+        pub use std::vec::*;
+
+        use crate::core::convert::{
+            transmute::{
+                TransmuteFrom,
+                options::{SafeTransmuteOptions, TransmuteOptions},
+            },
+            cast::{
+                CastFrom,
+                options::{
+                    SafeCastOptions,
+                    CastOptions,
+                },
+            },
+        };
+
+        /// **🌟** Safe options for casting `Vec<T>` to `Vec<U>`.
+        ///
+        /// Vec casting transmutes the contents of the vec. All [SafeTransmuteOptions] are [SafeVecCastOptions].
+        ///
+        /// See the [here][crate::core::convert::cast::CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] for examples.
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        pub trait SafeVecCastOptions
+            : SafeCastOptions
+            + SafeTransmuteOptions
+            + VecCastOptions
+        {}
+
+        /// **🌟** Options for casting `Vec<T>` to `Vec<U>`.
+        ///
+        /// Vec casting transmutes the contents of the vec. All [TransmuteOptions] are [VecCastOptions].
+        ///
+        /// See the [here][crate::core::convert::cast::CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] for examples.
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "cast", issue = "none"))]
+        pub trait VecCastOptions
+            : TransmuteOptions
+            + CastOptions
+        {}
+
+        #[cfg(feature = "nightly-marker-traits")]
+        impl<Neglect: SafeVecCastOptions> SafeCastOptions for Neglect {}
+        impl<Neglect: SafeTransmuteOptions> SafeVecCastOptions for Neglect {}
+
+        #[cfg(feature = "nightly-marker-traits")]
+        impl<Neglect: VecCastOptions> CastOptions for Neglect {}
+        impl<Neglect: TransmuteOptions> VecCastOptions for Neglect {}
+
+        use core::mem::{ManuallyDrop, size_of};
+        use crate::core::mem::AlignEq;
+        use crate::core::convert::transmute::options::NeglectStability;
+
+        // Stand-ins for the impls of `TransmuteFrom` that the compiler generates for the gadgets underlying the cast of byte arrays into bytes.
+        #[doc(hidden)]
+        unsafe impl<'a, const N: usize> TransmuteFrom<&'a [[u8; N]; usize::MAX], NeglectStability> for &'a [u8; usize::MAX] {}
+        #[doc(hidden)]
+        unsafe impl<'a, const N: usize> TransmuteFrom<&'a [[u8; N]; 0], NeglectStability> for &'a [u8; 0] {}
+        #[doc(hidden)]
+        unsafe impl<'a, const N: usize> TransmuteFrom<&'a [u8; 0], NeglectStability> for &'a [[u8; N]; 0] {}
+
+        /// #### `Vec<Src>` **🠮** `Vec<Dst>`
+        /// [`Vec::from_raw_parts`][Vec::from_raw_parts] requires that the static alignment of `Src` and `Dst` be equal, and that the size of the allocation, `capacity * size_of::<T>()`, be unchanged. We use the [AlignEq] trait, queried under [NeglectStability] alone so that [NeglectAlignment][crate::core::convert::transmute::options::NeglectAlignment] cannot relax it, to enforce the first invariant statically. The second is enforced at compile time by asserting that `size_of::<Src>()` is a multiple of `size_of::<Dst>()`: each `Src` is cast into a whole number of `Dst`s, and the length and capacity of the vec are scaled accordingly.
+        /// ##### Static Options
+        /// See [VecCastOptions] and [SafeVecCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::{cast::CastFrom, transmute::options::NeglectStability};
+        ///
+        /// let src : Vec<u32> = vec![1, 2, 3];
+        /// let dst : Vec<u32> = CastFrom::<_, NeglectStability>::cast_from(src);
+        /// assert_eq!(dst, [1, 2, 3]);
+        /// ```
+        /// A `Src` may be cast into several `Dst`s, without reallocating:
+        /// ```rust
+        /// use rfc::core::convert::{cast::CastFrom, transmute::options::NeglectStability};
+        ///
+        /// let mut src : Vec<[u8; 4]> = Vec::with_capacity(3);
+        /// src.push([1, 2, 3, 4]);
+        /// src.push([5, 6, 7, 8]);
+        /// let ptr = src.as_ptr() as *const u8;
+        ///
+        /// let dst : Vec<u8> = CastFrom::<_, NeglectStability>::cast_from(src);
+        /// assert_eq!(dst, [1, 2, 3, 4, 5, 6, 7, 8]);
+        /// assert_eq!(dst.len(), 8);
+        /// assert_eq!(dst.capacity(), 12);
+        /// assert_eq!(dst.as_ptr(), ptr);
+        /// ```
+        /// But a `Src` may not be split across multiple `Dst`s:
+        /// ```compile_fail,E0277
+        /// use rfc::core::convert::{cast::CastFrom, transmute::options::NeglectStability};
+        ///
+        /// let src : Vec<u8> = vec![1, 2, 3];
+        /// let dst : Vec<[u8; 2]> = CastFrom::<_, NeglectStability>::cast_from(src);
+        /// ```
+        /// Nor may [NeglectAlignment][crate::core::convert::transmute::options::NeglectAlignment] realign the allocation, which would later be freed with a different layout:
+        /// ```compile_fail,E0277
+        /// use rfc::core::convert::{cast::CastInto, transmute::options::NeglectAlignment};
+        ///
+        /// let src : Vec<u8> = vec![1, 2];
+        /// let dst : Vec<u16> = unsafe { CastInto::<_, NeglectAlignment>::unsafe_cast_into(src) };
+        /// ```
+        impl<Src, Dst, Neglect> CastFrom<Vec<Src>, Neglect> for Vec<Dst>
+        where
+            Neglect: VecCastOptions,
+            for<'a> &'a [Dst; usize::MAX]: TransmuteFrom<&'a [Src; usize::MAX], Neglect>,
+            Dst: AlignEq<Src, NeglectStability>,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: Vec<Src>) -> Vec<Dst>
+            {
+                const { assert!(size_of::<Src>().is_multiple_of(size_of::<Dst>()), "the size of the source type is not a multiple of the size of the destination type") };
+                let mut src = ManuallyDrop::new(src);
+                let (ptr, len, cap) = (src.as_mut_ptr(), src.len(), src.capacity());
+                let scale = size_of::<Src>().checked_div(size_of::<Dst>()).unwrap_or(1);
+                Vec::from_raw_parts(ptr as *mut Dst, len * scale, cap * scale)
+            }
+        }
+
+        use crate::core::convert::{
+            cast::{TryCastFrom, CastError, CastErrorKind, options::SwapBytes},
+            transmute::byteorder::{ByteOrder, Integer},
+        };
+
+        /// #### `&[u8]` **🠮** `Vec<Dst>`, swapping bytes
+        /// Each whole `size_of::<Dst>()` bytes of the source are read as `Dst`'s [byte-order-aware counterpart][Integer::InOrder] in byte order `O`, and converted into native byte order; so, the source need not be aligned. As with the [slice casts][CastFrom#impl-CastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D], trailing bytes are discarded; to reject them instead, use the [fallible cast][TryCastFrom#impl-TryCastFrom<%26%27i%20%5Bu8%5D%2C%20SwapBytes<O>>-for-Vec<Dst>].
+        ///
+        /// ##### Static Options
+        /// See [SwapBytes].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::{
+        ///     cast::{CastFrom, options::SwapBytes},
+        ///     transmute::byteorder::{BigEndian, LittleEndian},
+        /// };
+        ///
+        /// let src : &[u8] = &[0x12, 0x34, 0x56, 0x78, 0x9a][..];
+        ///
+        /// let dst : Vec<u32> = CastFrom::<_, SwapBytes<BigEndian>>::cast_from(src);
+        /// assert_eq!(dst, [0x1234_5678]);
+        ///
+        /// let dst : Vec<u16> = CastFrom::<_, SwapBytes<LittleEndian>>::cast_from(src);
+        /// assert_eq!(dst, [0x3412, 0x7856]);
+        /// ```
+        impl<'i, Dst, O> CastFrom<&'i [u8], SwapBytes<O>> for Vec<Dst>
+        where
+            O: ByteOrder,
+            Dst: Integer,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: &'i [u8]) -> Vec<Dst>
+            {
+                src.chunks_exact(size_of::<Dst>())
+                    .map(|chunk| {
+                        // Safe, because `Integer` is sealed, and each `InOrder` counterpart is a `size_of::<Dst>()`-byte array, of which every bit pattern is valid.
+                        let n = unsafe { (chunk.as_ptr() as *const Dst::InOrder<O>).read_unaligned() };
+                        n.into()
+                    })
+                    .collect()
+            }
+        }
+
+        /// #### `&[u8]` **🠮** `Vec<Dst>`, swapping bytes, fallibly
+        /// Unlike the [infallible cast][CastFrom#impl-CastFrom<%26%27i%20%5Bu8%5D%2C%20SwapBytes<O>>-for-Vec<Dst>], the length of the source must be a multiple of `size_of::<Dst>()`. The cast fails with [CastErrorKind::TrailingBytes], returning the source slice, if it is not.
+        ///
+        /// ##### Static Options
+        /// See [SwapBytes].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::{
+        ///     cast::{TryCastFrom, CastErrorKind, options::SwapBytes},
+        ///     transmute::byteorder::BigEndian,
+        /// };
+        ///
+        /// let src : &[u8] = &[0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0][..];
+        /// let dst : Vec<u32> = TryCastFrom::<_, SwapBytes<BigEndian>>::try_cast_from(src).unwrap();
+        /// assert_eq!(dst, [0x1234_5678, 0x9abc_def0]);
+        ///
+        /// let err = <Vec<u32> as TryCastFrom<_, SwapBytes<BigEndian>>>::try_cast_from(&src[..7]).unwrap_err();
+        /// assert_eq!(err.kind(), CastErrorKind::TrailingBytes);
+        /// assert_eq!(err.into_src(), &src[..7]);
+        /// ```
+        impl<'i, Dst, O> TryCastFrom<&'i [u8], SwapBytes<O>> for Vec<Dst>
+        where
+            O: ByteOrder,
+            Dst: Integer,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_try_cast_from(src: &'i [u8]) -> Result<Vec<Dst>, CastError<&'i [u8]>>
+            {
+                if src.len().is_multiple_of(size_of::<Dst>()) {
+                    Ok(unsafe { CastFrom::<_, SwapBytes<O>>::unsafe_cast_from(src) })
+                } else {
+                    Err(CastError::new(src, CastErrorKind::TrailingBytes))
+                }
+            }
+        }
+    }
+
+    /// A UTF-8 encoded, growable string, `String`.
+    pub mod string {
+        #[doc(no_inline)]
+        pub use std::string::*;
+
+        use crate::core::convert::cast::{
+            CastFrom,
+            TryCastFrom,
+            CastError,
+            CastErrorKind,
+        };
+        use crate::core::str::StrCastOptions;
+        use crate::std::vec::VecCastOptions;
+
+        /// #### `Vec<u8>` **🠮** `String`
+        /// The bytes are not checked to be valid UTF-8.
+        ///
+        /// ##### Static Options
+        /// See [StrCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::{cast::CastInto, transmute::options::NeglectValidity};
+        ///
+        /// let src : Vec<u8> = b"hello".to_vec();
+        /// // Safe, because `src` is valid UTF-8.
+        /// let dst : String = unsafe { CastInto::<_, NeglectValidity>::unsafe_cast_into(src) };
+        /// assert_eq!(dst, "hello");
+        /// ```
+        impl<Neglect> CastFrom<Vec<u8>, Neglect> for String
+        where
+            Neglect: StrCastOptions,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: Vec<u8>) -> String
+            {
+                unsafe { String::from_utf8_unchecked(src) }
+            }
+        }
+
+        /// #### `Vec<u8>` **🠮** `String`, fallibly
+        /// Unlike the [infallible cast][CastFrom#impl-CastFrom<Vec<u8>%2C%20Neglect>-for-String], the bytes are checked to be valid UTF-8. The cast fails with [CastErrorKind::Invalid], returning the source vec, if they are not.
+        ///
+        /// ##### Static Options
+        /// See [VecCastOptions] and [SafeVecCastOptions][crate::std::vec::SafeVecCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::cast::{TryCastInto, CastErrorKind};
+        ///
+        /// let src : Vec<u8> = b"hello".to_vec();
+        /// let dst : String = TryCastInto::<String>::try_cast_into(src).unwrap();
+        /// assert_eq!(dst, "hello");
+        ///
+        /// let src : Vec<u8> = vec![0xC0, 0x80];
+        /// let err = TryCastInto::<String>::try_cast_into(src).unwrap_err();
+        /// assert_eq!(err.kind(), CastErrorKind::Invalid);
+        /// assert_eq!(err.into_src(), vec![0xC0, 0x80]);
+        /// ```
+        impl<Neglect> TryCastFrom<Vec<u8>, Neglect> for String
+        where
+            Neglect: VecCastOptions,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_try_cast_from(src: Vec<u8>) -> Result<String, CastError<Vec<u8>>>
+            {
+                String::from_utf8(src).map_err(|err| CastError::new(err.into_bytes(), CastErrorKind::Invalid))
+            }
+        }
+
+        /// #### `String` **🠮** `Vec<u8>`
+        /// Every string is a valid sequence of bytes, so this cast is safe with any [SafeVecCastOptions][crate::std::vec::SafeVecCastOptions].
+        ///
+        /// ##### Static Options
+        /// See [VecCastOptions] and [SafeVecCastOptions][crate::std::vec::SafeVecCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::cast::CastInto;
+        ///
+        /// let src : String = String::from("hello");
+        /// let dst : Vec<u8> = CastInto::<Vec<u8>>::cast_into(src);
+        /// assert_eq!(dst, b"hello");
+        /// ```
+        impl<Neglect> CastFrom<String, Neglect> for Vec<u8>
         where
-            Neglect: SliceCastOptions,
-            &'o mut [Dst; 1]: TransmuteFrom<&'i mut [Src; usize::MAX], Neglect>
+            Neglect: VecCastOptions,
         {
             #[doc(hidden)]
             #[inline(always)]
-            unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> &'o mut [Dst]
+            unsafe fn unsafe_cast_from(src: String) -> Vec<u8>
             {
-                let len = size_of_val(src).checked_div(size_of::<Dst>()).unwrap_or(0);
-                unsafe { slice::from_raw_parts_mut(src.as_ptr() as *mut Dst, len) }
+                src.into_bytes()
+            }
+        }
+    }
+
+    /// A pointer type for heap allocation, `Box<T>`.
+    pub mod boxed {
+        #[doc(no_inline)]
+        pub use std::boxed::*;
+
+        use crate::core::convert::{
+            transmute::{TransmuteFrom, options::NeglectStability},
+            cast::CastFrom,
+        };
+        use crate::std::alloc::AllocCastOptions;
+
+        use crate::core::mem::{SizeEq, AlignEq};
+
+        /// #### `Box<[Src]>` **🠮** `Box<[Dst]>`
+        /// The slice is cast in place, without copying. `Src` and `Dst` must have equal size and alignment; see [casting owned pointers][crate::std::alloc#casting-owned-pointers].
+        /// ##### Static Options
+        /// See [AllocCastOptions] and [SafeAllocCastOptions][crate::std::alloc::SafeAllocCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::{cast::CastFrom, transmute::options::NeglectStability};
+        ///
+        /// let src : Box<[u32]> = vec![1, 2, 3].into_boxed_slice();
+        /// let dst : Box<[u32]> = CastFrom::<_, NeglectStability>::cast_from(src);
+        /// assert_eq!(&dst[..], &[1, 2, 3][..]);
+        /// ```
+        /// With the compiler's implementation of [TransmuteFrom], the element type may change:
+        /// ```rust,ignore
+        /// let src : Box<[i8]> = vec![-1, -2, -3, -4].into_boxed_slice();
+        /// let dst : Box<[u8]> = src.cast_into();
+        /// assert_eq!(&dst[..], &[255, 254, 253, 252][..]);
+        /// ```
+        impl<Src, Dst, Neglect> CastFrom<Box<[Src]>, Neglect> for Box<[Dst]>
+        where
+            Neglect: AllocCastOptions,
+            Dst: TransmuteFrom<Src, Neglect>
+               + AlignEq<Src, NeglectStability>
+               + SizeEq<Src, NeglectStability>,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: Box<[Src]>) -> Box<[Dst]>
+            {
+                Box::from_raw(Box::into_raw(src) as *mut [Dst])
+            }
+        }
+
+        /// #### `Box<Src>` **🠮** `Box<Dst>`
+        /// The value is reinterpreted without being moved out of its allocation, so `Dst` must occupy exactly the allocation `Src` did; see [casting owned pointers][crate::std::alloc#casting-owned-pointers].
+        /// ##### Static Options
+        /// See [AllocCastOptions] and [SafeAllocCastOptions][crate::std::alloc::SafeAllocCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use rfc::core::convert::{cast::CastFrom, transmute::options::NeglectStability};
+        ///
+        /// let src : Box<u32> = Box::new(42);
+        /// let dst : Box<u32> = CastFrom::<_, NeglectStability>::cast_from(src);
+        /// assert_eq!(*dst, 42);
+        /// ```
+        /// With the compiler's implementation of [TransmuteFrom], the referent type may change:
+        /// ```rust,ignore
+        /// let src : Box<[i8; 4]> = Box::new([-1, -2, -3, -4]);
+        /// let dst : Box<u32> = src.cast_into();
+        /// assert_eq!(*dst, u32::from_ne_bytes([255, 254, 253, 252]));
+        /// ```
+        impl<Src, Dst, Neglect> CastFrom<Box<Src>, Neglect> for Box<Dst>
+        where
+            Neglect: AllocCastOptions,
+            Dst: TransmuteFrom<Src, Neglect>
+               + AlignEq<Src, NeglectStability>
+               + SizeEq<Src, NeglectStability>,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: Box<Src>) -> Box<Dst>
+            {
+                Box::from_raw(Box::into_raw(src) as *mut Dst)
+            }
+        }
+
+        use crate::core::convert::transmute::{
+            stability::{PromiseTransmutableInto, PromiseTransmutableFrom, LayoutInfo, TypeLayout},
+            TransmuteInto,
+        };
+
+        /// A `Box<T>` forwards the stability promises of `T`, as a reference does:
+        /// ```rust
+        /// use rfc::core::convert::transmute::assert_transmutable;
+        ///
+        /// assert_transmutable!(Box<u32> => Box<u32>);
+        /// assert_transmutable!(Box<[u8; 4]> => Box<[u8; 4]>);
+        /// ```
+        impl<T: ?Sized> PromiseTransmutableInto for Box<T>
+        where
+            T: PromiseTransmutableInto,
+            T::Archetype: PromiseTransmutableInto<Archetype = T::Archetype>,
+            Box<T::Archetype>: TransmuteFrom<Self, NeglectStability>,
+        {
+            type Archetype = Box<T::Archetype>;
+        }
+
+        impl<T: ?Sized> PromiseTransmutableFrom for Box<T>
+        where
+            T: PromiseTransmutableFrom,
+            T::Archetype: PromiseTransmutableFrom<Archetype = T::Archetype>,
+            Box<T::Archetype>: TransmuteInto<Self, NeglectStability>,
+        {
+            type Archetype = Box<T::Archetype>;
+        }
+
+        impl<T: ?Sized> LayoutInfo for Box<T> {
+            fn layout_info() -> TypeLayout {
+                TypeLayout::of::<Self>()
+            }
+        }
+    }
+
+    /// Single-threaded reference-counting pointers, `Rc<T>`.
+    pub mod rc {
+        #[doc(no_inline)]
+        pub use std::rc::*;
+
+        use crate::core::convert::{
+            transmute::{TransmuteFrom, options::NeglectStability},
+            cast::CastFrom,
+        };
+        use crate::std::alloc::AllocCastOptions;
+
+        use crate::core::mem::{SizeEq, AlignEq};
+
+        /// #### `Rc<[Src]>` **🠮** `Rc<[Dst]>`
+        /// The slice is cast in place, without copying. `Src` and `Dst` must have equal size and alignment; see [casting owned pointers][crate::std::alloc#casting-owned-pointers].
+        /// ##### Static Options
+        /// See [AllocCastOptions] and [SafeAllocCastOptions][crate::std::alloc::SafeAllocCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use std::rc::Rc;
+        /// use rfc::core::convert::{cast::CastFrom, transmute::options::NeglectStability};
+        ///
+        /// let src : Rc<[u32]> = Rc::from(&[1, 2, 3][..]);
+        /// let dst : Rc<[u32]> = CastFrom::<_, NeglectStability>::cast_from(src);
+        /// assert_eq!(&dst[..], &[1, 2, 3][..]);
+        /// ```
+        /// With the compiler's implementation of [TransmuteFrom], the element type may change:
+        /// ```rust,ignore
+        /// let src : Rc<[i8]> = Rc::from(&[-1, -2, -3, -4][..]);
+        /// let dst : Rc<[u8]> = src.cast_into();
+        /// assert_eq!(&dst[..], &[255, 254, 253, 252][..]);
+        /// ```
+        impl<Src, Dst, Neglect> CastFrom<Rc<[Src]>, Neglect> for Rc<[Dst]>
+        where
+            Neglect: AllocCastOptions,
+            Dst: TransmuteFrom<Src, Neglect>
+               + AlignEq<Src, NeglectStability>
+               + SizeEq<Src, NeglectStability>,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: Rc<[Src]>) -> Rc<[Dst]>
+            {
+                Rc::from_raw(Rc::into_raw(src) as *const [Dst])
+            }
+        }
+    }
+
+    /// Synchronization primitives, including the atomically reference-counted pointer `Arc<T>`.
+    pub mod sync {
+        #[doc(no_inline)]
+        pub use std::sync::*;
+
+        use crate::core::convert::{
+            transmute::{TransmuteFrom, options::NeglectStability},
+            cast::CastFrom,
+        };
+        use crate::std::alloc::AllocCastOptions;
+
+        use crate::core::mem::{SizeEq, AlignEq};
+
+        /// #### `Arc<[Src]>` **🠮** `Arc<[Dst]>`
+        /// The slice is cast in place, without copying. `Src` and `Dst` must have equal size and alignment; see [casting owned pointers][crate::std::alloc#casting-owned-pointers].
+        /// ##### Static Options
+        /// See [AllocCastOptions] and [SafeAllocCastOptions][crate::std::alloc::SafeAllocCastOptions].
+        ///
+        /// ##### Example
+        /// ```rust
+        /// use std::sync::Arc;
+        /// use rfc::core::convert::{cast::CastFrom, transmute::options::NeglectStability};
+        ///
+        /// let src : Arc<[u32]> = Arc::from(&[1, 2, 3][..]);
+        /// let dst : Arc<[u32]> = CastFrom::<_, NeglectStability>::cast_from(src);
+        /// assert_eq!(&dst[..], &[1, 2, 3][..]);
+        /// ```
+        /// With the compiler's implementation of [TransmuteFrom], the element type may change:
+        /// ```rust,ignore
+        /// let src : Arc<[i8]> = Arc::from(&[-1, -2, -3, -4][..]);
+        /// let dst : Arc<[u8]> = src.cast_into();
+        /// assert_eq!(&dst[..], &[255, 254, 253, 252][..]);
+        /// ```
+        impl<Src, Dst, Neglect> CastFrom<Arc<[Src]>, Neglect> for Arc<[Dst]>
+        where
+            Neglect: AllocCastOptions,
+            Dst: TransmuteFrom<Src, Neglect>
+               + AlignEq<Src, NeglectStability>
+               + SizeEq<Src, NeglectStability>,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: Arc<[Src]>) -> Arc<[Dst]>
+            {
+                Arc::from_raw(Arc::into_raw(src) as *const [Dst])
+            }
+        }
+    }
+
+    /// Traits, helpers, and type definitions for core I/O functionality.
+    pub mod io {
+        #[doc(no_inline)]
+        pub use std::io::*;
+
+        use crate::core::convert::{
+            transmute::{AsBytes, FromBytes},
+            cast::CastFrom,
+        };
+
+        use core::{
+            mem::{MaybeUninit, size_of},
+            slice
+        };
+
+        /// **🌟** Read values that are [transmutable][crate::core::convert::transmute] from bytes.
+        ///
+        /// Implemented for every [Read]er. For instance, to read a header and its records from a file:
+        /// ```rust,ignore
+        /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+        /// #[repr(C)]
+        /// pub struct Header {
+        ///     pub tag: u32,
+        ///     pub len: u32,
+        /// }
+        ///
+        /// let mut file = File::open(path)?;
+        /// let header: Header = file.read_transmute()?;
+        /// let mut records = vec![0u64; header.len as usize];
+        /// file.read_slice_into(&mut records)?;
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "transmute_io", issue = "none"))]
+        pub trait ReadTransmuteExt: Read {
+            /// Read exactly `size_of::<T>()` bytes, as a `T`.
+            ///
+            /// Fails with [ErrorKind::UnexpectedEof] if the reader is exhausted first.
+            ///
+            /// ```rust
+            /// use std::io::{Cursor, ErrorKind};
+            /// use rfc::std::io::ReadTransmuteExt;
+            ///
+            /// let mut src = Cursor::new([1, 0, 0, 0, 2, 0]);
+            /// assert_eq!(src.read_transmute::<u32>()?, u32::from_ne_bytes([1, 0, 0, 0]));
+            /// assert_eq!(src.read_transmute::<u32>().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+            /// # Ok::<(), std::io::Error>(())
+            /// ```
+            fn read_transmute<T>(&mut self) -> Result<T>
+            where
+                T: FromBytes,
+            {
+                let mut value = MaybeUninit::<T>::zeroed();
+                // Safe, because every byte of `value` is initialized, if only to `0`.
+                let bytes = unsafe { slice::from_raw_parts_mut(value.as_mut_ptr() as *mut u8, size_of::<T>()) };
+                self.read_exact(bytes)?;
+                // Safe, because every sequence of bytes is a valid `T`.
+                Ok(unsafe { value.assume_init() })
+            }
+
+            /// Read exactly enough bytes to fill `dst`.
+            ///
+            /// The elements of `dst` are overwritten in place, by way of the [slice cast][CastFrom#impl-CastFrom<%26%27i%20mut%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20mut%20%5BDst%5D] of `&mut [T]` into `&mut [u8]`; this requires both that every byte of `T` is initialized, and that every sequence of bytes is a valid `T`. Fails with [ErrorKind::UnexpectedEof] if the reader is exhausted first, in which case the contents of `dst` are unspecified (but valid).
+            ///
+            /// ```rust
+            /// use std::io::{Cursor, ErrorKind};
+            /// use rfc::std::io::ReadTransmuteExt;
+            ///
+            /// let mut src = Cursor::new([1, 0, 2, 0, 3, 0, 4]);
+            /// let mut dst = [0u16; 3];
+            /// src.read_slice_into(&mut dst)?;
+            /// assert_eq!(dst.map(u16::from_le), [1, 2, 3]);
+            ///
+            /// let mut dst = [0u16; 1];
+            /// assert_eq!(src.read_slice_into(&mut dst).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+            /// # Ok::<(), std::io::Error>(())
+            /// ```
+            fn read_slice_into<T>(&mut self, dst: &mut [T]) -> Result<()>
+            where
+                for<'a> &'a mut [u8]: CastFrom<&'a mut [T]>,
+            {
+                self.read_exact(CastFrom::cast_from(dst))
+            }
+        }
+
+        impl<R: Read + ?Sized> ReadTransmuteExt for R {}
+
+        /// **🌟** Write values that are [transmutable][crate::core::convert::transmute] into bytes.
+        ///
+        /// Implemented for every [Write]r. Together with [ReadTransmuteExt], values may be round-tripped through any byte stream:
+        /// ```rust
+        /// use std::io::Cursor;
+        /// use rfc::std::io::{ReadTransmuteExt, WriteTransmuteExt};
+        ///
+        /// let mut buf = Cursor::new(Vec::new());
+        /// buf.write_transmute(&0xdead_beef_u32)?;
+        /// buf.write_transmute(&-1.5f64)?;
+        ///
+        /// buf.set_position(0);
+        /// assert_eq!(buf.read_transmute::<u32>()?, 0xdead_beef);
+        /// assert_eq!(buf.read_transmute::<f64>()?, -1.5);
+        /// # Ok::<(), std::io::Error>(())
+        /// ```
+        /// No unsafe code is required of the caller; the bounds of these methods suffice. Generic code may rely on them, too:
+        /// ```rust
+        /// use std::io::{self, Read, Write};
+        /// use rfc::core::convert::transmute::{AsBytes, FromBytes};
+        /// use rfc::std::io::{ReadTransmuteExt, WriteTransmuteExt};
+        ///
+        /// fn copy_one<T, R, W>(src: &mut R, dst: &mut W) -> io::Result<T>
+        /// where
+        ///     T: FromBytes + AsBytes,
+        ///     R: Read,
+        ///     W: Write,
+        /// {
+        ///     let value: T = src.read_transmute()?;
+        ///     dst.write_transmute(&value)?;
+        ///     Ok(value)
+        /// }
+        /// ```
+        #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "transmute_io", issue = "none"))]
+        pub trait WriteTransmuteExt: Write {
+            /// Write the `size_of::<T>()` bytes of `value`.
+            ///
+            /// ```rust
+            /// use std::fs::File;
+            /// use rfc::std::io::WriteTransmuteExt;
+            ///
+            /// let path = std::env::temp_dir().join(format!("write_transmute-{}", std::process::id()));
+            /// File::create(&path)?.write_transmute(&0x0102_0304_u32)?;
+            /// assert_eq!(std::fs::read(&path)?, 0x0102_0304_u32.to_ne_bytes());
+            /// std::fs::remove_file(&path)?;
+            /// # Ok::<(), std::io::Error>(())
+            /// ```
+            fn write_transmute<T>(&mut self, value: &T) -> Result<()>
+            where
+                T: AsBytes,
+            {
+                self.write_all(value.as_bytes())
+            }
+        }
+
+        impl<W: Write + ?Sized> WriteTransmuteExt for W {}
+    }
+
+    /// Filesystem manipulation operations.
+    pub mod fs {
+        #[doc(no_inline)]
+        pub use std::fs::*;
+
+        #[cfg(unix)]
+        pub use self::mmap::Mmap;
+
+        #[cfg(unix)]
+        mod mmap {
+            use crate::core::convert::{
+                transmute::options::NeglectAlignment,
+                cast::{CastFrom, CastError, CastErrorKind},
+            };
+
+            use core::{
+                convert::TryFrom,
+                mem::{align_of, size_of, size_of_val},
+                ops::Deref,
+                ptr::NonNull,
+                slice
+            };
+
+            use std::{
+                fs::File,
+                io,
+                os::unix::io::AsRawFd,
+            };
+
+            // The few declarations of `<sys/mman.h>` that `Mmap` requires. Their values agree across Linux, macOS and the BSDs.
+            mod sys {
+                use std::os::raw::{c_int, c_long, c_void};
+
+                pub const PROT_READ: c_int = 0x1;
+                pub const MAP_PRIVATE: c_int = 0x2;
+                pub const MAP_FAILED: *mut c_void = !0 as *mut c_void;
+
+                extern "C" {
+                    pub fn mmap(addr: *mut c_void, len: usize, prot: c_int, flags: c_int, fd: c_int, offset: c_long) -> *mut c_void;
+                    pub fn munmap(addr: *mut c_void, len: usize) -> c_int;
+                }
+            }
+
+            /// **🌟** A read-only, memory-mapped view of a file.
+            ///
+            /// An `Mmap` dereferences to the `[u8]` contents of the file, which may be viewed as a `[T]` with [view][Mmap::view]:
+            /// ```rust,ignore
+            /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+            /// #[repr(C)]
+            /// pub struct Entry {
+            ///     pub key: u64,
+            ///     pub offset: u64,
+            /// }
+            ///
+            /// let file = File::open("index.bin")?;
+            /// // Safe, so long as `index.bin` is not modified while it is mapped.
+            /// let map = unsafe { Mmap::map(&file)? };
+            /// let entries: &[Entry] = map.view().map_err(|err| err.kind())?;
+            /// ```
+            #[cfg_attr(all(doc, feature = "nightly-staged-api"), unstable(feature = "transmute_mmap", issue = "none"))]
+            pub struct Mmap {
+                ptr: NonNull<u8>,
+                len: usize,
+            }
+
+            // Safe, because the mapping is read-only.
+            unsafe impl Send for Mmap {}
+            unsafe impl Sync for Mmap {}
+
+            impl Mmap {
+                /// Map the entire contents of `file` into memory, read-only.
+                ///
+                /// The onus is on you to ensure that the file is not modified (by this process or any other) while it is mapped; such a modification would change the contents of the `[u8]` that the `Mmap` dereferences to.
+                ///
+                /// ```rust
+                /// use std::io::Write;
+                /// use rfc::std::fs::{File, Mmap};
+                ///
+                /// let path = std::env::temp_dir().join(format!("rfc-mmap-{}", std::process::id()));
+                /// File::create(&path)?.write_all(b"hello, world")?;
+                ///
+                /// let map = unsafe { Mmap::map(&File::open(&path)?)? };
+                /// assert_eq!(&map[..], b"hello, world");
+                /// # drop(map);
+                /// # std::fs::remove_file(&path)?;
+                /// # Ok::<(), std::io::Error>(())
+                /// ```
+                pub unsafe fn map(file: &File) -> io::Result<Mmap> {
+                    let len = usize::try_from(file.metadata()?.len())
+                        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "file is too large to map"))?;
+                    if len == 0 {
+                        // `mmap` rejects empty mappings.
+                        return Ok(Mmap { ptr: NonNull::dangling(), len });
+                    }
+                    let ptr = unsafe {
+                        sys::mmap(core::ptr::null_mut(), len, sys::PROT_READ, sys::MAP_PRIVATE, file.as_raw_fd(), 0)
+                    };
+                    if ptr == sys::MAP_FAILED {
+                        return Err(io::Error::last_os_error());
+                    }
+                    // `mmap` does not produce null on success.
+                    let ptr = unsafe { NonNull::new_unchecked(ptr as *mut u8) };
+                    Ok(Mmap { ptr, len })
+                }
+
+                /// View the mapped bytes as a `[T]`.
+                ///
+                /// The mapped bytes are cast by the [slice cast][CastFrom#impl-CastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D], neglecting alignment, so every sequence of bytes must be a valid `T`. In the manner of [NeglectAlignment], the alignment of the mapping is then checked dynamically, along with the absence of trailing bytes. The view fails, returning the mapped bytes, if:
+                ///  - the mapping is not aligned to `align_of::<T>()` ([CastErrorKind::Misaligned]), or
+                ///  - the size of the file is not a multiple of `size_of::<T>()` ([CastErrorKind::TrailingBytes]).
+                ///
+                /// Since mappings begin on a page boundary, the former is rare in practice.
+                pub fn view<'a, T>(&'a self) -> Result<&'a [T], CastError<&'a [u8]>>
+                where
+                    &'a [T]: CastFrom<&'a [u8], NeglectAlignment>,
+                {
+                    let bytes: &'a [u8] = self;
+                    if !(bytes.as_ptr() as usize).is_multiple_of(align_of::<T>()) {
+                        return Err(CastError::new(bytes, CastErrorKind::Misaligned));
+                    }
+                    if !size_of_val(bytes).is_multiple_of(size_of::<T>()) {
+                        return Err(CastError::new(bytes, CastErrorKind::TrailingBytes));
+                    }
+                    // Safe, because we dynamically enforce the alignment requirement, whose static check we chose to neglect.
+                    Ok(unsafe { CastFrom::<_, NeglectAlignment>::unsafe_cast_from(bytes) })
+                }
+            }
+
+            impl Deref for Mmap {
+                type Target = [u8];
+
+                fn deref(&self) -> &[u8] {
+                    // Safe, because the `len` bytes at `ptr` remain mapped (and unmodified) for the lifetime of `self`.
+                    unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+                }
+            }
+
+            impl Drop for Mmap {
+                fn drop(&mut self) {
+                    if self.len != 0 {
+                        unsafe { sys::munmap(self.ptr.as_ptr() as *mut _, self.len) };
+                    }
+                }
+            }
+        }
+    }
+
+}
+
+/// An executable model of the rules by which [TransmuteFrom][crate::core::convert::transmute::TransmuteFrom] is implemented.
+///
+/// The compiler implements [TransmuteFrom][crate::core::convert::transmute::TransmuteFrom] automatically, by comparing the layouts of the source and destination types. This module models those layouts in userland, so that the rules of [soundness, safety and stability](crate::core::convert::transmute) may be evaluated (and tested!) without compiler support.
+///
+/// A [Layout][layout::Layout] models a type as a set of alternative sequences of [Byte][layout::Byte]s: one alternative for each of the ways its bytes might be validly initialized. A scalar typically has one alternative, a union has one for each of its fields, and an enum has one for each of its variants. A struct has one for each combination of the alternatives of its fields; these are represented without being enumerated, by a finite automaton that reads each field's bytes in turn.
+///
+/// ## Example
+/// The crate-level example, modeled:
+/// ```rust
+/// use rfc::layout::*;
+///
+/// // #[derive(PromiseTransmutableInto, PromiseTransmutableFrom)]
+/// // #[repr(C)]
+/// // pub struct Foo(pub u8, pub u16);
+/// let foo = Layout::repr_c_struct(&[Layout::int(1)?, Layout::int(2)?]).promise_transmutable();
+/// let u32 = Layout::int(4)?;
+///
+/// // This transmutation is accepted:
+/// assert_eq!(u32.is_transmutable_into(&foo, Options::NONE), Answer::Yes);
+///
+/// // But this transmutation is rejected, because of the padding byte at offset 1:
+/// assert_eq!(
+///     foo.is_transmutable_into(&u32, Options::NONE),
+///     Answer::No(Reason::UninitIntoInit { offset: 1 }),
+/// );
+/// # Ok::<(), LayoutError>(())
+/// ```
+/// Validity is modeled bytewise, and may be neglected:
+/// ```rust
+/// use rfc::layout::*;
+///
+/// let neglect_validity = Options { neglect_validity: true, ..Options::NONE };
+///
+/// assert!(Layout::bool().is_transmutable_into(&Layout::int(1)?, Options::NONE).is_yes());
+/// assert!(!Layout::int(1)?.is_transmutable_into(&Layout::bool(), Options::NONE).is_yes());
+/// assert!(Layout::int(1)?.is_transmutable_into(&Layout::bool(), neglect_validity).is_yes());
+///
+/// // Even with validity neglected, transmutations that cannot possibly be valid are rejected:
+/// let foo = Layout::repr_int_enum(1, &[(24, &[])])?.promise_transmutable();
+/// let bar = Layout::repr_int_enum(1, &[(42, &[])])?.promise_transmutable();
+/// assert_eq!(
+///     foo.is_transmutable_into(&bar, neglect_validity),
+///     Answer::No(Reason::InvalidValue { offset: 0 }),
+/// );
+/// # Ok::<(), LayoutError>(())
+/// ```
+/// As are references:
+/// ```rust
+/// use rfc::layout::*;
+///
+/// let neglect_alignment = Options { neglect_alignment: true, ..Options::NONE };
+///
+/// let bytes = Layout::reference(Layout::array(&Layout::int(1)?, 2), false);
+/// let short = Layout::reference(Layout::int(2)?, false);
+///
+/// assert!(short.is_transmutable_into(&bytes, Options::NONE).is_yes());
+/// assert_eq!(
+///     bytes.is_transmutable_into(&short, Options::NONE),
+///     Answer::No(Reason::AlignmentIncrease { offset: 0, src: 1, dst: 2 }),
+/// );
+/// assert!(bytes.is_transmutable_into(&short, neglect_alignment).is_yes());
+/// # Ok::<(), LayoutError>(())
+/// ```
+pub mod layout {
+    use std::{collections::BTreeSet, ops::RangeInclusive, rc::Rc};
+
+    /// The state of a single byte of a [Layout].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Byte {
+        /// A byte that may be uninitialized; e.g., padding, or the contents of a `MaybeUninit`.
+        Uninit,
+        /// An initialized byte, whose value is within the given range.
+        Init(RangeInclusive<u8>),
+        /// The byte at the given index of a pointer.
+        Pointer(Pointer, usize),
+    }
+
+    /// The referent of a reference.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Pointer {
+        /// The layout of the type being pointed to.
+        pub referent: Rc<Layout>,
+        /// Whether the reference is `&mut`.
+        pub mutable: bool,
+    }
+
+    /// Whether a layout carries the promises of [PromiseTransmutableInto][crate::core::convert::transmute::stability::PromiseTransmutableInto] and [PromiseTransmutableFrom][crate::core::convert::transmute::stability::PromiseTransmutableFrom].
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct Promise {
+        /// The layout is promised to be stably transmutable *into* other types.
+        pub into: bool,
+        /// The layout is promised to be stably transmutable *from* other types.
+        pub from: bool,
+    }
+
+    /// The layout of a type, modeled as a set of alternative byte sequences.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Layout {
+        size: usize,
+        align: usize,
+        bytes: Nfa,
+        promise: Promise,
+    }
+
+    /// An error produced when a [Layout] is constructed with an impossible size or alignment.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum LayoutError {
+        /// The size of a scalar exceeds 16 bytes, the size of the largest primitive integer.
+        ScalarTooLarge {
+            /// The size of the scalar.
+            size: usize,
+        },
+        /// The alignment is not a power of two.
+        InvalidAlignment {
+            /// The alignment.
+            align: usize,
+        },
+    }
+
+    impl std::fmt::Display for LayoutError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match *self {
+                LayoutError::ScalarTooLarge { size } =>
+                    write!(f, "a scalar of {} bytes is larger than the largest primitive integer", size),
+                LayoutError::InvalidAlignment { align } =>
+                    write!(f, "the alignment {} is not a power of two", align),
+            }
+        }
+    }
+
+    impl std::error::Error for LayoutError {}
+
+    /// The static checks to neglect; the model's counterpart of [transmute::options][crate::core::convert::transmute::options].
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct Options {
+        /// Models [NeglectStability][crate::core::convert::transmute::options::NeglectStability].
+        pub neglect_stability: bool,
+        /// Models [NeglectAlignment][crate::core::convert::transmute::options::NeglectAlignment].
+        pub neglect_alignment: bool,
+        /// Models [NeglectValidity][crate::core::convert::transmute::options::NeglectValidity].
+        pub neglect_validity: bool,
+    }
+
+    impl Options {
+        /// Neglect nothing; models `()`.
+        pub const NONE: Self = Options {
+            neglect_stability: false,
+            neglect_alignment: false,
+            neglect_validity: false,
+        };
+    }
+
+    /// Whether a transmutation is accepted.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Answer {
+        /// The transmutation is accepted.
+        Yes,
+        /// The transmutation is rejected.
+        No(Reason),
+    }
+
+    impl Answer {
+        /// Produces `true` if the transmutation is accepted.
+        pub fn is_yes(&self) -> bool {
+            *self == Answer::Yes
+        }
+    }
+
+    /// Why a transmutation is rejected; see also [explain][crate::core::convert::transmute::explain].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum Reason {
+        /// The source type is not promised to be stably transmutable *into* other types.
+        NotPromisedInto,
+        /// The destination type is not promised to be stably transmutable *from* other types.
+        NotPromisedFrom,
+        /// The destination type is larger than the source type.
+        SizeIncrease {
+            /// The size of the source type.
+            src: usize,
+            /// The size of the destination type.
+            dst: usize,
+        },
+        /// A possibly-uninitialized byte of the source is mapped onto an initialized byte of the destination.
+        UninitIntoInit {
+            /// The offset of the byte.
+            offset: usize,
+        },
+        /// A byte of the source may have a value that is invalid for the destination.
+        InvalidValue {
+            /// The offset of the byte.
+            offset: usize,
+        },
+        /// A byte of a pointer is mapped onto a byte that is not the same byte of a pointer, or vice versa.
+        PointerMismatch {
+            /// The offset of the byte.
+            offset: usize,
+        },
+        /// A shared reference is mapped onto a mutable reference.
+        MutabilityIncrease {
+            /// The offset of the reference.
+            offset: usize,
+        },
+        /// A reference is mapped onto a reference whose referent has a greater minimum alignment.
+        AlignmentIncrease {
+            /// The offset of the reference.
+            offset: usize,
+            /// The alignment of the source referent.
+            src: usize,
+            /// The alignment of the destination referent.
+            dst: usize,
+        },
+        /// A reference is mapped onto a reference to a larger referent.
+        ReferentSizeIncrease {
+            /// The offset of the reference.
+            offset: usize,
+            /// The size of the source referent.
+            src: usize,
+            /// The size of the destination referent.
+            dst: usize,
+        },
+        /// The referents of a pair of references are not transmutable.
+        Referent {
+            /// The offset of the reference.
+            offset: usize,
+            /// Why the referents are not transmutable.
+            reason: Box<Reason>,
+        },
+    }
+
+    impl Reason {
+        /// The byte offset at which the transmutation fails, if the reason concerns a particular byte.
+        pub fn offset(&self) -> Option<usize> {
+            match *self {
+                Reason::NotPromisedInto
+                | Reason::NotPromisedFrom
+                | Reason::SizeIncrease { .. } => None,
+                Reason::UninitIntoInit { offset }
+                | Reason::InvalidValue { offset }
+                | Reason::PointerMismatch { offset }
+                | Reason::MutabilityIncrease { offset }
+                | Reason::AlignmentIncrease { offset, .. }
+                | Reason::ReferentSizeIncrease { offset, .. }
+                | Reason::Referent { offset, .. } => Some(offset),
             }
         }
+    }
 
-        /// #### `&mut [Src]` **🠮** `&[Dst]`
-        /// If the sizes of `Src` and `Dst` differ, the length of the output slice is adjusted as-needed.
-        ///
-        /// ##### Static Options
-        /// See [SliceCastOptions] and [SafeSliceCastOptions].
+    impl std::fmt::Display for Reason {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Reason::NotPromisedInto =>
+                    write!(f, "the source type is not promised to be transmutable into other types"),
+                Reason::NotPromisedFrom =>
+                    write!(f, "the destination type is not promised to be transmutable from other types"),
+                Reason::SizeIncrease { src, dst } =>
+                    write!(f, "the destination type ({} bytes) is larger than the source type ({} bytes)", dst, src),
+                Reason::UninitIntoInit { offset } =>
+                    write!(f, "byte {} may be uninitialized in the source type, but must be initialized in the destination type", offset),
+                Reason::InvalidValue { offset } =>
+                    write!(f, "byte {} may have a value in the source type that is invalid in the destination type", offset),
+                Reason::PointerMismatch { offset } =>
+                    write!(f, "byte {} is part of a pointer in only one of the source and destination types", offset),
+                Reason::MutabilityIncrease { offset } =>
+                    write!(f, "the shared reference at byte {} is transmuted into a mutable reference", offset),
+                Reason::AlignmentIncrease { offset, src, dst } =>
+                    write!(f, "the reference at byte {} is transmuted from a referent aligned to {} bytes into a referent aligned to {} bytes", offset, src, dst),
+                Reason::ReferentSizeIncrease { offset, src, dst } =>
+                    write!(f, "the reference at byte {} is transmuted from a referent of {} bytes into a referent of {} bytes", offset, src, dst),
+                Reason::Referent { offset, reason } =>
+                    write!(f, "the referent of the reference at byte {} is not transmutable: {}", offset, reason),
+            }
+        }
+    }
+
+    impl Layout {
+        /// The layout of `!`: zero-sized, with no valid instances.
+        pub fn never() -> Self {
+            Layout { size: 0, align: 1, bytes: Nfa::never(), promise: Promise { into: true, from: true } }
+        }
+
+        /// The layout of `()`.
+        pub fn unit() -> Self {
+            Layout { size: 0, align: 1, bytes: Nfa::unit(), promise: Promise { into: true, from: true } }
+        }
+
+        /// The layout of a little-endian scalar whose valid values are those within `valid`.
         ///
-        /// ##### Example
+        /// Values are interpreted as unsigned; e.g., the valid values of `NonZeroI8` are `1..=255`. Scalars may be no larger than 16 bytes, and must be aligned to a power of two:
         /// ```rust
-        /// let src : &mut [i8] = &mut [-1, -2, -3, -4][..];
-        /// let dst : &[[u8; 2]] = src.cast_into();
-        /// assert_eq!(dst, &[[-255, -254], [-253, -252]][..]);
+        /// use rfc::layout::*;
+        ///
+        /// assert_eq!(Layout::scalar(17, 1, &[0..=u128::MAX]), Err(LayoutError::ScalarTooLarge { size: 17 }));
+        /// assert_eq!(Layout::scalar(4, 0, &[0..=u128::MAX]), Err(LayoutError::InvalidAlignment { align: 0 }));
+        /// assert_eq!(Layout::scalar(4, 3, &[0..=u128::MAX]), Err(LayoutError::InvalidAlignment { align: 3 }));
+        /// assert_eq!(Layout::int(0), Err(LayoutError::InvalidAlignment { align: 0 }));
+        /// assert_eq!(Layout::int(16).map(|int| int.size()), Ok(16));
         /// ```
-        impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], Neglect> for &'o [Dst]
-        where
-            Neglect: SliceCastOptions,
-            &'o [Dst; 1]: TransmuteFrom<&'i mut [Src; usize::MAX], Neglect>
-        {
-            #[doc(hidden)]
-            #[inline(always)]
-            unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> &'o [Dst]
-            {
-                let len = size_of_val(src).checked_div(size_of::<Dst>()).unwrap_or(0);
-                unsafe {
-                    slice::from_raw_parts(src.as_ptr() as *const Dst, len)
-                }
+        pub fn scalar(size: usize, align: usize, valid: &[RangeInclusive<u128>]) -> Result<Self, LayoutError> {
+            if size > 16 {
+                return Err(LayoutError::ScalarTooLarge { size });
+            }
+            if !align.is_power_of_two() {
+                return Err(LayoutError::InvalidAlignment { align });
             }
+            Ok(Self::scalar_unchecked(size, align, valid))
         }
-    }
-}
 
-/// Additions to `libstd`
-pub mod std {
-    /// A contiguous growable array type with heap-allocated contents, `Vec<T>`.
-    pub mod vec {
-        #[doc(no_inline)]
-        pub use std::vec::*;
+        // Like `scalar`, but for a `size` and `align` already known to be valid.
+        fn scalar_unchecked(size: usize, align: usize, valid: &[RangeInclusive<u128>]) -> Self {
+            let max = if size >= 16 { u128::MAX } else { (1u128 << (size * 8)) - 1 };
+            let alternatives: Vec<Nfa> = valid
+                .iter()
+                .filter(|range| range.start() <= range.end() && *range.start() <= max)
+                .flat_map(|range| {
+                    let lo = range.start().to_be_bytes();
+                    let hi = (*range.end()).min(max).to_be_bytes();
+                    split_range(&lo[16 - size..], &hi[16 - size..])
+                })
+                .map(|big_endian| Nfa::bytes(big_endian.into_iter().rev().map(Byte::Init)))
+                .collect();
+            Layout { size, align, bytes: Nfa::union(&alternatives), promise: Promise { into: true, from: true } }
+        }
 
-        use crate::core::convert::{
-            transmute::{
-                TransmuteFrom,
-                options::{SafeTransmuteOptions, TransmuteOptions, NeglectAlignment},
-            },
-            cast::{
-                CastFrom,
-                options::{
-                    SafeCastOptions,
-                    CastOptions,
-                },
-            },
-        };
+        /// The layout of a naturally-aligned integer of the given size, for which every bit pattern is valid.
+        pub fn int(size: usize) -> Result<Self, LayoutError> {
+            Self::scalar(size, size, &[0..=u128::MAX])
+        }
 
-        /// **🌟** Safe options for casting `Vec<T>` to `Vec<U>`.
+        /// The layout of a naturally-aligned `NonZero*` integer of the given size.
+        pub fn nonzero(size: usize) -> Result<Self, LayoutError> {
+            Self::scalar(size, size, &[1..=u128::MAX])
+        }
+
+        /// The layout of `bool`.
+        pub fn bool() -> Self {
+            Self::scalar_unchecked(1, 1, &[0..=1])
+        }
+
+        /// The layout of `char`.
+        pub fn char() -> Self {
+            Self::scalar_unchecked(4, 4, &[0..=0xD7FF, 0xE000..=0x10FFFF])
+        }
+
+        /// The layout of `MaybeUninit<T>`, given the layout of `T`.
+        pub fn maybe_uninit(of: &Layout) -> Self {
+            Layout {
+                size: of.size,
+                align: of.align,
+                bytes: Nfa::bytes(vec![Byte::Uninit; of.size]),
+                promise: of.promise,
+            }
+        }
+
+        /// The layout of `&T` (or `&mut T`, if `mutable`), given the layout of `T`.
+        pub fn reference(referent: Layout, mutable: bool) -> Self {
+            let size = std::mem::size_of::<usize>();
+            let promise = referent.promise;
+            let pointer = Pointer { referent: Rc::new(referent), mutable };
+            Layout {
+                size,
+                align: size,
+                bytes: Nfa::bytes((0..size).map(|index| Byte::Pointer(pointer.clone(), index))),
+                promise,
+            }
+        }
+
+        /// The layout of `[T; N]`, given the layout of `T`.
+        pub fn array(element: &Layout, n: usize) -> Self {
+            let mut array = Self::repr_c_struct(&vec![element.clone(); n]);
+            array.align = element.align;
+            array.promise = element.promise;
+            array
+        }
+
+        /// The layout of a `#[repr(C)]` struct with the given fields.
         ///
-        /// Vec casting transmutes the contents of the vec. All [SafeTransmuteOptions] are [SafeVecCastOptions].
+        /// Padding is inserted as needed to align each field. Like a struct produced by any other means, the resulting layout is not promised to be stable.
         ///
-        /// See the [here][crate::core::convert::cast::CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] for examples.
-        #[cfg_attr(doc, unstable(feature = "cast", issue = "none"))]
-        pub trait SafeVecCastOptions
-            : SafeCastOptions
-            + SafeTransmuteOptions
-            + VecCastOptions
-        {}
+        /// The fields of a struct vary independently, so the struct's layout is built from its fields' layouts without enumerating their combinations; e.g., a struct of many `NonZeroU32`s, each of which has several alternatives, is no more costly to model than a struct of as many `u32`s:
+        /// ```rust
+        /// use rfc::layout::*;
+        ///
+        /// let neglect_stability = Options { neglect_stability: true, ..Options::NONE };
+        /// let nonzeros = Layout::repr_c_struct(&vec![Layout::nonzero(4)?; 200]);
+        /// let ints = Layout::repr_c_struct(&vec![Layout::int(4)?; 200]);
+        ///
+        /// assert!(nonzeros.is_transmutable_into(&ints, neglect_stability).is_yes());
+        /// assert_eq!(
+        ///     ints.is_transmutable_into(&nonzeros, neglect_stability),
+        ///     Answer::No(Reason::InvalidValue { offset: 3 }),
+        /// );
+        /// # Ok::<(), LayoutError>(())
+        /// ```
+        pub fn repr_c_struct(fields: &[Layout]) -> Self {
+            let align = fields.iter().map(|field| field.align).max().unwrap_or(1);
+            let mut bytes = Nfa::unit();
+            let mut size = 0;
+            for field in fields {
+                let padding = padding_needed(size, field.align);
+                bytes = bytes.then_bytes(vec![Byte::Uninit; padding]).then(&field.bytes);
+                size += padding + field.size;
+            }
+            let padding = padding_needed(size, align);
+            let bytes = bytes.then_bytes(vec![Byte::Uninit; padding]);
+            Layout { size: size + padding, align, bytes, promise: Promise::default() }
+        }
 
-        /// **🌟** Options for casting `Vec<T>` to `Vec<U>`.
+        /// The layout of a `#[repr(C)]` union with the given fields.
         ///
-        /// Vec casting transmutes the contents of the vec. All [TransmuteOptions] are [VecCastOptions].
+        /// A union is valid if any of its fields is valid; bytes beyond the end of a field may be uninitialized.
+        pub fn repr_c_union(fields: &[Layout]) -> Self {
+            let align = fields.iter().map(|field| field.align).max().unwrap_or(1);
+            let size = fields.iter().map(|field| field.size).max().unwrap_or(0);
+            let size = size + padding_needed(size, align);
+            let alternatives: Vec<Nfa> = fields
+                .iter()
+                .map(|field| field.bytes.clone().then_bytes(vec![Byte::Uninit; size - field.size]))
+                .collect();
+            Layout { size, align, bytes: Nfa::union(&alternatives), promise: Promise::default() }
+        }
+
+        /// The layout of an enum with a primitive representation (e.g., `#[repr(u8)]`), given the size of its discriminant, and the discriminant and fields of each variant.
         ///
-        /// See the [here][crate::core::convert::cast::CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] for examples.
-        #[cfg_attr(doc, unstable(feature = "cast", issue = "none"))]
-        pub trait VecCastOptions
-            : TransmuteOptions
-            + CastOptions
-        {}
+        /// Each variant is laid out as a `#[repr(C)]` struct whose first field is the discriminant, which must be of the size of a primitive integer:
+        /// ```rust
+        /// use rfc::layout::*;
+        ///
+        /// assert_eq!(Layout::repr_int_enum(3, &[]), Err(LayoutError::InvalidAlignment { align: 3 }));
+        /// assert_eq!(Layout::repr_int_enum(32, &[(0, &[])]), Err(LayoutError::ScalarTooLarge { size: 32 }));
+        /// ```
+        pub fn repr_int_enum(tag_size: usize, variants: &[(u128, &[Layout])]) -> Result<Self, LayoutError> {
+            // Validate the size of the discriminant, even if there are no variants.
+            Self::int(tag_size)?;
+            let variants = variants
+                .iter()
+                .map(|&(discriminant, fields)| {
+                    let tag = Self::scalar(tag_size, tag_size, &[discriminant..=discriminant])?;
+                    let fields: Vec<Layout> = std::iter::once(tag).chain(fields.iter().cloned()).collect();
+                    Ok(Self::repr_c_struct(&fields))
+                })
+                .collect::<Result<Vec<Layout>, LayoutError>>()?;
+            let mut layout = Self::repr_c_union(&variants);
+            layout.align = layout.align.max(tag_size);
+            Ok(layout)
+        }
 
-        impl<Neglect: SafeVecCastOptions> SafeCastOptions for Neglect {}
-        impl<Neglect: SafeTransmuteOptions> SafeVecCastOptions for Neglect {}
+        /// Promise that this layout may be stably transmuted *into* other types.
+        pub fn promise_transmutable_into(mut self) -> Self {
+            self.promise.into = true;
+            self
+        }
 
-        impl<Neglect: VecCastOptions> CastOptions for Neglect {}
-        impl<Neglect: TransmuteOptions> VecCastOptions for Neglect {}
+        /// Promise that this layout may be stably transmuted *from* other types.
+        pub fn promise_transmutable_from(mut self) -> Self {
+            self.promise.from = true;
+            self
+        }
 
-        use core::mem::MaybeUninit;
-        use crate::core::mem::{SizeEq, AlignEq};
+        /// Promise that this layout may be stably transmuted both *into* and *from* other types.
+        pub fn promise_transmutable(self) -> Self {
+            self.promise_transmutable_into().promise_transmutable_from()
+        }
 
-        /// #### `Vec<Src>` **🠮** `Vec<Dst>`
-        /// [`Vec::from_raw_parts`][Vec::from_raw_parts] requires that the size and static alignment of `Src` and `Dst` be equal. [NeglectAlignment] is therefore ignored. We use the [AlignEq] and [SizeEq] traits to enforce these invariants statically. 
-        /// ##### Static Options
-        /// See [VecCastOptions] and [SafeVecCastOptions].
+        /// The size of this layout, in bytes.
+        pub fn size(&self) -> usize {
+            self.size
+        }
+
+        /// The minimum alignment of this layout, in bytes.
+        pub fn align(&self) -> usize {
+            self.align
+        }
+
+        /// The stability promises of this layout.
+        pub fn promise(&self) -> Promise {
+            self.promise
+        }
+
+        /// Determine whether a value with this layout may be transmuted into a value with the layout `dst`.
         ///
-        /// ##### Example
+        /// A transmutation is accepted if:
+        ///  - unless stability is neglected, `self` is promised to be transmutable *into* other types, and `dst` is promised to be transmutable *from* other types,
+        ///  - `dst` is no larger than `self`, and
+        ///  - every valid instance of `self` is bytewise compatible with some alternative of `dst`; or, if validity is neglected, *some* instance of `self` is.
+        ///
+        /// A byte of `self` is compatible with a byte of `dst` if:
+        ///  - the `dst` byte may be uninitialized, or
+        ///  - both bytes are initialized, and the value of the `self` byte is a value of the `dst` byte, or
+        ///  - both bytes are the same byte of a reference, and the `self` reference may be converted into the `dst` reference: it is not a shared reference converted into a mutable one, its referent is no smaller and (unless alignment is neglected) no less aligned, and its referent is transmutable into the `dst` referent (and back, if mutable).
+        pub fn is_transmutable_into(&self, dst: &Layout, options: Options) -> Answer {
+            match self.explain_transmutable_into(dst, options).into_iter().next() {
+                None => Answer::Yes,
+                Some(reason) => Answer::No(reason),
+            }
+        }
+
+        /// Explain every reason that a value with this layout may not be transmuted into a value with the layout `dst`; the model's counterpart of [explain][crate::core::convert::transmute::explain].
+        ///
+        /// Produces nothing if the transmutation is accepted. Otherwise, produces the stability reasons, followed by each distinct reason that a valid instance of `self` is not compatible with `dst`, at the first byte at which it fails to be (if validity is neglected, only the reason concerning the furthest byte, and only if *every* instance fails).
         /// ```rust
-        /// let src : Vec<i8> = vec![-1, -2, -3, -4];
-        /// let dst : Vec<u8> = src.cast_into();
-        /// assert_eq!(dst, vec![255, 254, 253, 252]);
+        /// use rfc::layout::*;
+        ///
+        /// // #[repr(C)]
+        /// // pub struct Bar(pub u8, pub bool, pub u16);
+        /// let bar = Layout::repr_c_struct(&[Layout::int(1)?, Layout::bool(), Layout::int(2)?]);
+        ///
+        /// assert_eq!(
+        ///     Layout::int(4)?.explain_transmutable_into(&bar, Options::NONE),
+        ///     vec![Reason::NotPromisedFrom, Reason::InvalidValue { offset: 1 }],
+        /// );
+        /// # Ok::<(), LayoutError>(())
         /// ```
-        impl<Src, Dst, Neglect> CastFrom<Vec<Src>, Neglect> for Vec<Dst>
-        where
-            Neglect: VecCastOptions,
-            Dst: TransmuteFrom<Src, Neglect>
-               + AlignEq<Src, Neglect>
-               + SizeEq<Src, Neglect>,
-        {
-            #[doc(hidden)]
-            #[inline(always)]
-            unsafe fn unsafe_cast_from(src: Vec<Src>) -> Vec<Dst>
-            {
-                let (ptr, len, cap) = src.into_raw_parts();
-                Vec::from_raw_parts(ptr as *mut Dst, len, cap)
+        pub fn explain_transmutable_into(&self, dst: &Layout, options: Options) -> Vec<Reason> {
+            let mut reasons = vec![];
+
+            if !options.neglect_stability {
+                if !self.promise.into {
+                    reasons.push(Reason::NotPromisedInto);
+                }
+                if !dst.promise.from {
+                    reasons.push(Reason::NotPromisedFrom);
+                }
+            }
+
+            if dst.size > self.size {
+                reasons.push(Reason::SizeIncrease { src: self.size, dst: dst.size });
+                return reasons;
+            }
+
+            reasons.extend(Check::new(&self.bytes, &dst.bytes, dst.size, options).run());
+            reasons
+        }
+    }
+
+    // The valid byte sequences of a layout, as a nondeterministic finite automaton: each path from `start` to `accept` spells out one valid sequence. Every path to a given state has the same length, and no transition leads into `start` or out of `accept`.
+    //
+    // A struct's automaton is the concatenation of its fields' automata, so it grows with the sum, not the product, of the alternatives of its fields.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Nfa {
+        transitions: Vec<Vec<(Byte, usize)>>,
+        start: usize,
+        accept: usize,
+    }
+
+    impl Nfa {
+        // Accepts only the empty sequence.
+        fn unit() -> Self {
+            Nfa { transitions: vec![vec![]], start: 0, accept: 0 }
+        }
+
+        // Accepts nothing.
+        fn never() -> Self {
+            Nfa { transitions: vec![vec![], vec![]], start: 0, accept: 1 }
+        }
+
+        // Accepts exactly the given sequence of bytes.
+        fn bytes(bytes: impl IntoIterator<Item = Byte>) -> Self {
+            Self::unit().then_bytes(bytes)
+        }
+
+        // Accepts any of the sequences accepted by `alternatives`, which must all be of the same length.
+        fn union<'a>(alternatives: impl IntoIterator<Item = &'a Nfa>) -> Self {
+            let mut nfa = Self::never();
+            for alternative in alternatives {
+                // Zero-length alternatives either accept the empty sequence or nothing at all.
+                if alternative.start == alternative.accept {
+                    return Self::unit();
+                }
+                nfa.embed(alternative, nfa.start, Some(nfa.accept));
+            }
+            nfa
+        }
+
+        // Accepts the sequences of `self`, followed by those of `next`.
+        fn then(mut self, next: &Nfa) -> Self {
+            self.accept = self.embed(next, self.accept, None);
+            self
+        }
+
+        // Accepts the sequences of `self`, followed by the given sequence of bytes.
+        fn then_bytes(mut self, bytes: impl IntoIterator<Item = Byte>) -> Self {
+            for byte in bytes {
+                let next = self.transitions.len();
+                self.transitions.push(vec![]);
+                self.transitions[self.accept].push((byte, next));
+                self.accept = next;
+            }
+            self
+        }
+
+        // Copy the transitions of `other` into `self`, merging its start state into `start` and (if given) its accept state into `accept`. Produces the state into which its accept state was merged.
+        fn embed(&mut self, other: &Nfa, start: usize, accept: Option<usize>) -> usize {
+            let base = self.transitions.len();
+            let map = |state: usize| match accept {
+                _ if state == other.start => start,
+                Some(accept) if state == other.accept => accept,
+                _ => base + state,
+            };
+            self.transitions.resize(base + other.transitions.len(), vec![]);
+            for (state, transitions) in other.transitions.iter().enumerate() {
+                for (byte, next) in transitions {
+                    self.transitions[map(state)].push((byte.clone(), map(*next)));
+                }
+            }
+            map(other.accept)
+        }
+
+        // For each state, whether `accept` is reachable from it.
+        fn live(&self) -> Vec<bool> {
+            let mut predecessors = vec![vec![]; self.transitions.len()];
+            for (state, transitions) in self.transitions.iter().enumerate() {
+                for (_, next) in transitions {
+                    predecessors[*next].push(state);
+                }
+            }
+            let mut live = vec![false; self.transitions.len()];
+            let mut pending = vec![self.accept];
+            while let Some(state) = pending.pop() {
+                if !std::mem::replace(&mut live[state], true) {
+                    pending.extend(&predecessors[state]);
+                }
+            }
+            live
+        }
+    }
+
+    // The number of padding bytes needed to align `offset` to `align`. Every alignment is a power of two; `scalar` rejects any other.
+    fn padding_needed(offset: usize, align: usize) -> usize {
+        (align - offset % align) % align
+    }
+
+    // Decompose the range of big-endian integers `lo..=hi` into a set of sequences of per-byte ranges.
+    fn split_range(lo: &[u8], hi: &[u8]) -> Vec<Vec<RangeInclusive<u8>>> {
+        fn prefixed(head: RangeInclusive<u8>, tails: Vec<Vec<RangeInclusive<u8>>>) -> impl Iterator<Item = Vec<RangeInclusive<u8>>> {
+            tails.into_iter().map(move |tail| std::iter::once(head.clone()).chain(tail).collect())
+        }
+
+        let (lo_head, lo_tail) = match lo.split_first() {
+            Some(split) => split,
+            None => return vec![vec![]],
+        };
+        let (hi_head, hi_tail) = hi.split_first().unwrap();
+
+        if lo_head == hi_head {
+            return prefixed(*lo_head..=*hi_head, split_range(lo_tail, hi_tail)).collect();
+        }
+
+        let lo_tail_is_min = lo_tail.iter().all(|&b| b == u8::MIN);
+        let hi_tail_is_max = hi_tail.iter().all(|&b| b == u8::MAX);
+        let min = vec![u8::MIN; lo_tail.len()];
+        let max = vec![u8::MAX; hi_tail.len()];
+
+        let mut ranges = vec![];
+        if !lo_tail_is_min {
+            ranges.extend(prefixed(*lo_head..=*lo_head, split_range(lo_tail, &max)));
+        }
+        let start = if lo_tail_is_min { *lo_head } else { *lo_head + 1 };
+        let end = if hi_tail_is_max { *hi_head } else { *hi_head - 1 };
+        if start <= end {
+            ranges.extend(prefixed(start..=end, split_range(&min, &max)));
+        }
+        if !hi_tail_is_max {
+            ranges.extend(prefixed(*hi_head..=*hi_head, split_range(&min, hi_tail)));
+        }
+        ranges
+    }
+
+    // Of two reasons, the one concerning the later byte.
+    fn further(reason: Option<Reason>, other: Reason) -> Reason {
+        match reason {
+            Some(reason) if reason.offset() > other.offset() => reason,
+            _ => other,
+        }
+    }
+
+    // A check that every byte sequence of `src` (or, if validity is neglected, *some* byte sequence of `src`) is compatible with some byte sequence of `dst`, up to the `size` of `dst`.
+    //
+    // The check reads the automaton of `src` a byte at a time, tracking each pair of a `src` state and the set of `dst` states that the bytes read so far may have led to. Pairs reached by several paths are only tracked once, so a struct's fields are each checked once, rather than once per combination of the alternatives of the fields before them.
+    struct Check<'a> {
+        src: &'a Nfa,
+        dst: &'a Nfa,
+        src_live: Vec<bool>,
+        dst_live: Vec<bool>,
+        size: usize,
+        options: Options,
+    }
+
+    impl<'a> Check<'a> {
+        fn new(src: &'a Nfa, dst: &'a Nfa, size: usize, options: Options) -> Self {
+            Check { src, dst, src_live: src.live(), dst_live: dst.live(), size, options }
+        }
+
+        // The reasons that `src` is not compatible with `dst`: each distinct reason that a path of `src` fails, at the first byte at which it fails; or, if validity is neglected and every path fails, the reason concerning the furthest byte.
+        fn run(&self) -> Vec<Reason> {
+            // A layout with no valid instances is compatible with anything, and nothing is compatible with a layout with no valid instances.
+            if !self.src_live[self.src.start] {
+                return vec![];
             }
+            if !self.dst_live[self.dst.start] {
+                return vec![Reason::InvalidValue { offset: 0 }];
+            }
+
+            let mut reasons = vec![];
+            let mut furthest = None;
+            let mut frontier: BTreeSet<(usize, Vec<usize>)> = std::iter::once((self.src.start, vec![self.dst.start])).collect();
+            for offset in 0..self.size {
+                let mut next_frontier = BTreeSet::new();
+                for (src, dst) in &frontier {
+                    for (byte, next) in self.src.transitions[*src].iter().filter(|(_, next)| self.src_live[*next]) {
+                        for piece in self.step(byte, dst, offset) {
+                            match piece {
+                                Ok(dst) => {
+                                    next_frontier.insert((*next, dst));
+                                }
+                                Err(reason) if self.options.neglect_validity => furthest = Some(further(furthest, reason)),
+                                Err(reason) => if !reasons.contains(&reason) {
+                                    reasons.push(reason);
+                                }
+                            }
+                        }
+                    }
+                }
+                frontier = next_frontier;
+                if frontier.is_empty() {
+                    break;
+                }
+            }
+
+            // If validity is neglected, one compatible path suffices.
+            match furthest {
+                Some(reason) if frontier.is_empty() => vec![reason],
+                _ => reasons,
+            }
+        }
+
+        // The ways in which the `src` byte at `offset` may be read by the bytes leading out of the `dst` states: for each piece of the range of values the `src` byte may take, either the (live) `dst` states that the piece may lead to, or why there are none.
+        fn step(&self, src: &Byte, dst: &[usize], offset: usize) -> Vec<Result<Vec<usize>, Reason>> {
+            let transitions: Vec<&(Byte, usize)> = dst
+                .iter()
+                .flat_map(|&state| &self.dst.transitions[state])
+                .filter(|(_, next)| self.dst_live[*next])
+                .collect();
+            let targets = |accepts: &dyn Fn(&Byte) -> bool| {
+                let mut targets: Vec<usize> = transitions.iter().filter(|(byte, _)| accepts(byte)).map(|(_, next)| *next).collect();
+                targets.sort_unstable();
+                targets.dedup();
+                targets
+            };
+
+            match src {
+                Byte::Uninit => {
+                    let targets = targets(&|dst| *dst == Byte::Uninit);
+                    if targets.is_empty() {
+                        return vec![Err(if transitions.iter().any(|(dst, _)| matches!(dst, Byte::Pointer(..))) {
+                            Reason::PointerMismatch { offset }
+                        } else {
+                            Reason::UninitIntoInit { offset }
+                        })];
+                    }
+                    vec![Ok(targets)]
+                }
+                Byte::Init(values) => {
+                    // The boundaries at which the ranges of values of the `dst` bytes begin or end.
+                    let mut bounds: Vec<u16> = vec![*values.start() as u16, *values.end() as u16 + 1];
+                    for (dst, _) in &transitions {
+                        if let Byte::Init(range) = dst {
+                            bounds.extend(&[*range.start() as u16, *range.end() as u16 + 1]);
+                        }
+                    }
+                    bounds.retain(|bound| (*values.start() as u16..=*values.end() as u16 + 1).contains(bound));
+                    bounds.sort_unstable();
+                    bounds.dedup();
+
+                    let only_pointers = !transitions.is_empty() && transitions.iter().all(|(dst, _)| matches!(dst, Byte::Pointer(..)));
+                    bounds
+                        .windows(2)
+                        .map(|piece| piece[0] as u8..=(piece[1] - 1) as u8)
+                        .map(|piece| {
+                            let targets = targets(&|dst| match dst {
+                                Byte::Uninit => true,
+                                Byte::Init(range) => range.start() <= piece.start() && piece.end() <= range.end(),
+                                Byte::Pointer(..) => self.options.neglect_validity,
+                            });
+                            if !targets.is_empty() {
+                                Ok(targets)
+                            } else if only_pointers {
+                                Err(Reason::PointerMismatch { offset })
+                            } else {
+                                Err(Reason::InvalidValue { offset })
+                            }
+                        })
+                        .collect()
+                }
+                Byte::Pointer(src_pointer, src_index) => {
+                    let mut first = None;
+                    let mut targets = vec![];
+                    for (dst, next) in &transitions {
+                        let compatible = match dst {
+                            Byte::Uninit => Ok(()),
+                            Byte::Pointer(dst_pointer, dst_index) if dst_index == src_index => {
+                                if *src_index == 0 {
+                                    pointers_compatible(src_pointer, dst_pointer, self.options).map_err(|reason| match reason {
+                                        Reason::MutabilityIncrease { .. } => Reason::MutabilityIncrease { offset },
+                                        Reason::AlignmentIncrease { src, dst, .. } => Reason::AlignmentIncrease { offset, src, dst },
+                                        Reason::ReferentSizeIncrease { src, dst, .. } => Reason::ReferentSizeIncrease { offset, src, dst },
+                                        reason => Reason::Referent { offset, reason: Box::new(reason) },
+                                    })
+                                } else {
+                                    Ok(())
+                                }
+                            }
+                            _ => Err(Reason::PointerMismatch { offset }),
+                        };
+                        match compatible {
+                            Ok(()) => targets.push(*next),
+                            Err(reason) => first = first.or(Some(reason)),
+                        }
+                    }
+                    if targets.is_empty() {
+                        return vec![Err(first.unwrap_or(Reason::PointerMismatch { offset }))];
+                    }
+                    targets.sort_unstable();
+                    targets.dedup();
+                    vec![Ok(targets)]
+                }
+            }
+        }
+    }
+
+    // Determine whether the reference `src` may be converted into the reference `dst`. Stability is checked for the reference as a whole, so it is neglected for the referents.
+    fn pointers_compatible(src: &Pointer, dst: &Pointer, options: Options) -> Result<(), Reason> {
+        if dst.mutable && !src.mutable {
+            return Err(Reason::MutabilityIncrease { offset: 0 });
+        }
+        let (src_referent, dst_referent) = (&*src.referent, &*dst.referent);
+        if !options.neglect_alignment && dst_referent.align > src_referent.align {
+            return Err(Reason::AlignmentIncrease { offset: 0, src: src_referent.align, dst: dst_referent.align });
+        }
+        if dst_referent.size > src_referent.size {
+            return Err(Reason::ReferentSizeIncrease { offset: 0, src: src_referent.size, dst: dst_referent.size });
+        }
+        let options = Options { neglect_stability: true, ..options };
+        if let Answer::No(reason) = src_referent.is_transmutable_into(dst_referent, options) {
+            return Err(reason);
+        }
+        // Writes through a `&mut Dst` are observable through the `&mut Src` it was transmuted from.
+        if dst.mutable {
+            if let Answer::No(reason) = dst_referent.is_transmutable_into(src_referent, options) {
+                return Err(reason);
+            }
+        }
+        Ok(())
+    }
+
+    /// A type whose layout is modeled by a [Layout].
+    ///
+    /// This trait is implemented for the primitive types, and for arrays, references and the wrapper types of `core` whenever the types they contain implement it. It may be implemented for other types by composing the [Layout]s of their fields; e.g.:
+    /// ```rust
+    /// use rfc::layout::{Layout, Modeled};
+    ///
+    /// // #[derive(PromiseTransmutableInto, PromiseTransmutableFrom)]
+    /// #[repr(C)]
+    /// pub struct Foo(pub u8, pub u16);
+    ///
+    /// impl Modeled for Foo {
+    ///     fn layout() -> Layout {
+    ///         Layout::repr_c_struct(&[u8::layout(), u16::layout()]).promise_transmutable()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Foo::layout().size(), core::mem::size_of::<Foo>());
+    /// ```
+    /// Modeled types may be explained with [explain][crate::core::convert::transmute::explain].
+    pub trait Modeled {
+        /// The layout of `Self`.
+        fn layout() -> Layout;
+    }
+
+    macro_rules! modeled_scalar {
+        ($($T:ty => $valid:expr),* $(,)?) => {$(
+            impl Modeled for $T {
+                fn layout() -> Layout {
+                    Layout::scalar_unchecked(std::mem::size_of::<$T>(), std::mem::align_of::<$T>(), &$valid)
+                }
+            }
+        )*};
+    }
+
+    modeled_scalar! {
+        u8 => [0..=u128::MAX], u16 => [0..=u128::MAX], u32 => [0..=u128::MAX], u64 => [0..=u128::MAX], u128 => [0..=u128::MAX], usize => [0..=u128::MAX],
+        i8 => [0..=u128::MAX], i16 => [0..=u128::MAX], i32 => [0..=u128::MAX], i64 => [0..=u128::MAX], i128 => [0..=u128::MAX], isize => [0..=u128::MAX],
+        f32 => [0..=u128::MAX], f64 => [0..=u128::MAX],
+        bool => [0..=1],
+        char => [0..=0xD7FF, 0xE000..=0x10FFFF],
+    }
+
+    macro_rules! modeled_nonzero {
+        ($($NonZero:ident),*) => {$(
+            modeled_scalar! {
+                std::num::$NonZero => [1..=u128::MAX],
+                Option<std::num::$NonZero> => [0..=u128::MAX],
+            }
+        )*};
+    }
+
+    modeled_nonzero!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+    modeled_nonzero!(NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize);
+
+    impl Modeled for () {
+        fn layout() -> Layout {
+            Layout::unit()
+        }
+    }
+
+    impl<T: ?Sized> Modeled for std::marker::PhantomData<T> {
+        fn layout() -> Layout {
+            Layout::unit()
+        }
+    }
+
+    impl<T: Modeled, const N: usize> Modeled for [T; N] {
+        fn layout() -> Layout {
+            Layout::array(&T::layout(), N)
+        }
+    }
+
+    impl<T: Modeled> Modeled for &T {
+        fn layout() -> Layout {
+            Layout::reference(T::layout(), false)
+        }
+    }
+
+    impl<T: Modeled> Modeled for &mut T {
+        fn layout() -> Layout {
+            Layout::reference(T::layout(), true)
+        }
+    }
+
+    impl<T: Modeled> Modeled for std::mem::MaybeUninit<T> {
+        fn layout() -> Layout {
+            Layout::maybe_uninit(&T::layout())
         }
     }
 
-}
\ No newline at end of file
+    // These wrappers are laid out exactly as the types they wrap.
+    macro_rules! modeled_wrapper {
+        ($($Wrapper:ident)::*) => {
+            impl<T: Modeled> Modeled for $($Wrapper)::*<T> {
+                fn layout() -> Layout {
+                    T::layout()
+                }
+            }
+        };
+    }
+
+    modeled_wrapper!(std::num::Wrapping);
+    modeled_wrapper!(std::cell::Cell);
+    modeled_wrapper!(std::cell::UnsafeCell);
+    modeled_wrapper!(std::mem::ManuallyDrop);
+}